@@ -0,0 +1,369 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use leptos::prelude::{TimeoutHandle, document, set_timeout_with_handle, window};
+use leptos::wasm_bindgen::JsCast;
+use leptos::wasm_bindgen::closure::Closure;
+use leptos::web_sys::js_sys::Array;
+use leptos::web_sys::{
+    AddEventListenerOptions, Element, EventTarget, IntersectionObserver, IntersectionObserverEntry,
+    IntersectionObserverInit, ResizeObserver,
+};
+
+use crate::dom::{bounding_client_rect, overflow_ancestors};
+use crate::{Rect, Reference};
+
+/// Which changes trigger [`auto_update`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoUpdateOptions {
+    /// Update when any overflow ancestor of either element scrolls
+    pub ancestor_scroll: bool,
+    /// Update when the window resizes
+    pub ancestor_resize: bool,
+    /// Update when either element resizes, using a `ResizeObserver`
+    pub element_resize: bool,
+    /// Update when the reference moves without scrolling or resizing, e.g. when content
+    /// above it loads, using an `IntersectionObserver`
+    pub layout_shift: bool,
+    /// Update on every animation frame in which the reference moved, e.g. while it is
+    /// animated with CSS transforms; replaces `layout_shift`
+    pub animation_frame: bool,
+}
+
+impl Default for AutoUpdateOptions {
+    fn default() -> Self {
+        Self {
+            ancestor_scroll: true,
+            ancestor_resize: true,
+            element_resize: true,
+            layout_shift: true,
+            animation_frame: false,
+        }
+    }
+}
+
+/// Listeners registered by [`auto_update`], removed when dropped
+pub struct AutoUpdateCleanup {
+    // Only held for its `Drop` implementation
+    _guard: Box<dyn Any>,
+}
+
+impl AutoUpdateCleanup {
+    /// Wrap a guard that stops updates when dropped, for
+    /// [`Platform::auto_update`](crate::Platform::auto_update) implementations
+    pub fn new(guard: impl Any) -> Self {
+        Self {
+            _guard: Box::new(guard),
+        }
+    }
+}
+
+impl std::fmt::Debug for AutoUpdateCleanup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AutoUpdateCleanup").finish_non_exhaustive()
+    }
+}
+
+/// DOM listeners and observers, removed when dropped
+struct DomListeners {
+    update: Closure<dyn FnMut()>,
+    listeners: Vec<(EventTarget, &'static str)>,
+    resize_observer: Option<ResizeObserver>,
+    layout_shift: Option<LayoutShiftObserver>,
+    frame_loop: Option<FrameLoop>,
+}
+
+impl Drop for DomListeners {
+    fn drop(&mut self) {
+        let callback = self.update.as_ref().unchecked_ref();
+        for (target, event) in &self.listeners {
+            let _ = target.remove_event_listener_with_callback(event, callback);
+        }
+        if let Some(resize_observer) = &self.resize_observer {
+            resize_observer.disconnect();
+        }
+        if let Some(layout_shift) = &self.layout_shift {
+            layout_shift.disconnect();
+        }
+        if let Some(frame_loop) = &self.frame_loop {
+            frame_loop.cancel();
+        }
+    }
+}
+
+/// Call `update` whenever the position of the floating element may have changed
+///
+/// Listens to scroll events on every overflow ancestor of both elements, window
+/// resizes, size changes of the elements themselves and layout shifts moving the
+/// reference, or checks the reference on every animation frame when
+/// `animation_frame` is set. The listeners stay registered until the returned
+/// [`AutoUpdateCleanup`] is dropped.
+///
+/// The reference is an element or a [`VirtualElement`](crate::VirtualElement). A virtual
+/// reference is only watched for scrolling through the ancestors of its context element, and for
+/// moving on every animation frame.
+pub fn auto_update(
+    reference: impl Into<Reference<Element>>,
+    floating: &Element,
+    update: impl Fn() + 'static,
+    options: AutoUpdateOptions,
+) -> AutoUpdateCleanup {
+    let reference = reference.into();
+    let update: Rc<dyn Fn()> = Rc::new(update);
+    let (reference_element, context_element) = match &reference {
+        Reference::Element(element) => (Some(element), Some(element)),
+        Reference::Virtual(virtual_element) => (None, virtual_element.context_element()),
+    };
+
+    let layout_shift = reference_element
+        .filter(|_| options.layout_shift && !options.animation_frame)
+        .map(|element| LayoutShiftObserver::new(element.clone(), Rc::clone(&update)));
+
+    let frame_loop = options.animation_frame.then(|| {
+        let measure: Rc<dyn Fn() -> Rect> = match &reference {
+            Reference::Element(element) => {
+                let element = element.clone();
+                Rc::new(move || bounding_client_rect(&element))
+            }
+            Reference::Virtual(virtual_element) => {
+                let virtual_element = virtual_element.clone();
+                Rc::new(move || virtual_element.bounding_client_rect())
+            }
+        };
+        FrameLoop::start(measure, Rc::clone(&update))
+    });
+
+    let update = Closure::<dyn FnMut()>::new(move || update());
+    let callback = update.as_ref().unchecked_ref();
+    let window = window();
+
+    let mut listeners = Vec::new();
+    if options.ancestor_scroll {
+        let mut targets: Vec<EventTarget> = Vec::new();
+        for ancestor in context_element
+            .map(overflow_ancestors)
+            .unwrap_or_default()
+            .into_iter()
+            .chain(overflow_ancestors(floating))
+        {
+            let ancestor: EventTarget = ancestor.into();
+            if !targets.contains(&ancestor) {
+                targets.push(ancestor);
+            }
+        }
+        targets.push(window.clone().into());
+
+        let listener_options = AddEventListenerOptions::new();
+        listener_options.set_passive(true);
+        for target in targets {
+            let _ = target.add_event_listener_with_callback_and_add_event_listener_options(
+                "scroll",
+                callback,
+                &listener_options,
+            );
+            listeners.push((target, "scroll"));
+        }
+    }
+    if options.ancestor_resize {
+        let _ = window.add_event_listener_with_callback("resize", callback);
+        listeners.push((window.into(), "resize"));
+    }
+
+    let resize_observer = options
+        .element_resize
+        .then(|| ResizeObserver::new(callback).ok())
+        .flatten();
+    if let Some(resize_observer) = &resize_observer {
+        if let Some(reference) = reference_element {
+            resize_observer.observe(reference);
+        }
+        resize_observer.observe(floating);
+    }
+
+    AutoUpdateCleanup::new(DomListeners {
+        update,
+        listeners,
+        resize_observer,
+        layout_shift,
+        frame_loop,
+    })
+}
+
+/// Frame callback that schedules itself again, taken out to stop the loop
+type FrameCallback = Rc<RefCell<Option<Closure<dyn FnMut()>>>>;
+
+/// Calls `on_move` on every animation frame in which the measured rect changed
+struct FrameLoop {
+    frame_id: Rc<Cell<Option<i32>>>,
+    callback: FrameCallback,
+}
+
+impl FrameLoop {
+    fn start(measure: Rc<dyn Fn() -> Rect>, on_move: Rc<dyn Fn()>) -> Self {
+        let frame_id = Rc::new(Cell::new(None));
+        let callback: FrameCallback = Rc::new(RefCell::new(None));
+
+        let mut previous_rect = measure();
+        *callback.borrow_mut() = Some(Closure::new({
+            let frame_id = Rc::clone(&frame_id);
+            let weak_callback = Rc::downgrade(&callback);
+
+            move || {
+                let rect = measure();
+                if rect != previous_rect {
+                    on_move();
+                }
+                previous_rect = rect;
+
+                if let Some(callback) = weak_callback.upgrade() {
+                    frame_id.set(request_frame(&callback));
+                }
+            }
+        }));
+        frame_id.set(request_frame(&callback));
+
+        Self { frame_id, callback }
+    }
+
+    fn cancel(&self) {
+        if let Some(frame_id) = self.frame_id.take() {
+            let _ = window().cancel_animation_frame(frame_id);
+        }
+        self.callback.borrow_mut().take();
+    }
+}
+
+fn request_frame(callback: &RefCell<Option<Closure<dyn FnMut()>>>) -> Option<i32> {
+    let callback = callback.borrow();
+    let callback = callback.as_ref()?;
+    window()
+        .request_animation_frame(callback.as_ref().unchecked_ref())
+        .ok()
+}
+
+/// Detects the reference moving on the page, following Floating UI's `observeMove`
+///
+/// The observer's root margin is shrunk to exactly the reference's rect, so any
+/// movement changes its intersection ratio and triggers a refresh, which calls
+/// `on_move` and re-creates the observer around the new rect.
+struct LayoutShiftObserver(Rc<RefCell<LayoutShiftState>>);
+
+struct LayoutShiftState {
+    element: Element,
+    on_move: Rc<dyn Fn()>,
+    observer: Option<IntersectionObserver>,
+    callback: Option<Closure<dyn FnMut(Array)>>,
+    timeout: Option<TimeoutHandle>,
+}
+
+impl LayoutShiftObserver {
+    fn new(element: Element, on_move: Rc<dyn Fn()>) -> Self {
+        let state = Rc::new(RefCell::new(LayoutShiftState {
+            element,
+            on_move,
+            observer: None,
+            callback: None,
+            timeout: None,
+        }));
+        refresh(&state, true, 1.0);
+        Self(state)
+    }
+
+    fn disconnect(&self) {
+        self.0.borrow_mut().disconnect();
+    }
+}
+
+impl LayoutShiftState {
+    fn disconnect(&mut self) {
+        if let Some(timeout) = self.timeout.take() {
+            timeout.clear();
+        }
+        if let Some(observer) = self.observer.take() {
+            observer.disconnect();
+        }
+        self.callback = None;
+    }
+}
+
+fn refresh(state: &Rc<RefCell<LayoutShiftState>>, skip: bool, threshold: f64) {
+    let (element, on_move) = {
+        let mut state = state.borrow_mut();
+        state.disconnect();
+        (state.element.clone(), Rc::clone(&state.on_move))
+    };
+
+    let rect = bounding_client_rect(&element);
+    if !skip {
+        on_move();
+    }
+    if rect.width == 0.0 || rect.height == 0.0 {
+        return;
+    }
+    let Some(root) = document().document_element() else {
+        return;
+    };
+
+    let inset_top = rect.y.floor();
+    let inset_right = (f64::from(root.client_width()) - rect.right()).floor();
+    let inset_bottom = (f64::from(root.client_height()) - rect.bottom()).floor();
+    let inset_left = rect.x.floor();
+    let root_margin = format!(
+        "{}px {}px {}px {}px",
+        -inset_top, -inset_right, -inset_bottom, -inset_left
+    );
+
+    let init = IntersectionObserverInit::new();
+    init.set_root_margin(&root_margin);
+    init.set_threshold(&threshold.clamp(0.0, 1.0).into());
+
+    let weak = Rc::downgrade(state);
+    let observed = element.clone();
+    let mut is_first_update = true;
+    let callback = Closure::<dyn FnMut(Array)>::new(move |entries: Array| {
+        let Some(state) = weak.upgrade() else {
+            return;
+        };
+        let ratio = entries
+            .get(0)
+            .unchecked_into::<IntersectionObserverEntry>()
+            .intersection_ratio();
+
+        if ratio != threshold {
+            if !is_first_update {
+                return refresh(&state, false, 1.0);
+            }
+            if ratio == 0.0 {
+                // The reference is fully outside the root margin, retry with a tiny threshold
+                let weak = Rc::downgrade(&state);
+                let timeout = set_timeout_with_handle(
+                    move || {
+                        if let Some(state) = weak.upgrade() {
+                            refresh(&state, false, 1e-7);
+                        }
+                    },
+                    Duration::from_secs(1),
+                );
+                state.borrow_mut().timeout = timeout.ok();
+            } else {
+                refresh(&state, false, ratio);
+            }
+        }
+        if ratio == 1.0 && rect != bounding_client_rect(&observed) {
+            refresh(&state, false, 1.0);
+        }
+        is_first_update = false;
+    });
+
+    let Ok(observer) =
+        IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+    else {
+        return;
+    };
+    observer.observe(&element);
+    let mut state = state.borrow_mut();
+    state.observer = Some(observer);
+    state.callback = Some(callback);
+}
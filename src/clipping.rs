@@ -0,0 +1,139 @@
+use leptos::prelude::document;
+use leptos::web_sys::Element;
+
+use crate::dom::{
+    bounding_client_rect, computed_style, is_containing_block, is_overflow_element, parent_element,
+};
+use crate::{Boundary, DomPlatform, Platform, Rect, RootBoundary};
+
+/// Ancestors whose overflow clips the element, closest first
+///
+/// These are the ancestors with `overflow: hidden`, `auto`, `scroll` or `clip` that the element
+/// does not escape, up to but excluding `<body>`. A `position: absolute` element escapes static
+/// overflow ancestors outside of its containing block, and a `position: fixed` element, or any
+/// element inside a `position: fixed` ancestor, escapes every ancestor that is not a containing
+/// block, e.g. through a `transform`.
+pub fn clipping_ancestors(element: &Element) -> Vec<Element> {
+    let mut ancestors = Vec::new();
+    let element_is_fixed = css_position(element) == "fixed";
+    // Position of the closest element that has not escaped its ancestors so far, `None` once a
+    // fixed element escaped all of them
+    let mut containing_position = None;
+    let mut current = if element_is_fixed {
+        parent_element(element)
+    } else {
+        Some(element.clone())
+    };
+
+    while let Some(node) = current {
+        if is_last_traversable(&node) {
+            break;
+        }
+
+        let position = css_position(&node);
+        let is_containing = is_containing_block(&node);
+        if !is_containing && position == "fixed" {
+            containing_position = None;
+        }
+
+        let escaped = if element_is_fixed {
+            !is_containing && containing_position.is_none()
+        } else {
+            let escapes_static = !is_containing
+                && position == "static"
+                && matches!(containing_position.as_deref(), Some("absolute" | "fixed"));
+            let inside_fixed = is_overflow_element(&node)
+                && !is_containing
+                && has_fixed_position_ancestor(element, &node);
+            escapes_static || inside_fixed
+        };
+
+        if !escaped {
+            if &node != element && is_overflow_element(&node) {
+                ancestors.push(node.clone());
+            }
+            containing_position = Some(position);
+        }
+        current = parent_element(&node);
+    }
+
+    ancestors
+}
+
+/// Area the element is visible in, relative to the viewport
+///
+/// With [`Boundary::ClippingAncestors`] and [`RootBoundary::Viewport`] this is the viewport
+/// intersected with the padding box of every ancestor in [`clipping_ancestors`]. This is the
+/// boundary [`flip`](crate::flip), [`shift`](crate::shift), [`size`](crate::size) and
+/// [`hide`](crate::hide) check against.
+pub fn get_clipping_rect(
+    element: &Element,
+    boundary: &Boundary,
+    root_boundary: &RootBoundary,
+) -> Rect {
+    let viewport = document()
+        .document_element()
+        .map_or(Rect::UNBOUNDED, |root| Rect {
+            x: 0.0,
+            y: 0.0,
+            width: root.client_width().into(),
+            height: root.client_height().into(),
+        });
+
+    let boundary = match boundary {
+        Boundary::ClippingAncestors => clipping_ancestors(element)
+            .iter()
+            .fold(Rect::UNBOUNDED, |clipping_rect, ancestor| {
+                clipping_rect.intersection(&client_rect(ancestor))
+            }),
+        Boundary::Rect(rect) => *rect,
+    };
+    let root_boundary = match root_boundary {
+        RootBoundary::Viewport => viewport,
+        RootBoundary::Rect(rect) => *rect,
+    };
+
+    boundary.intersection(&root_boundary)
+}
+
+/// Padding box of an element relative to the viewport, excluding borders and scrollbars
+fn client_rect(element: &Element) -> Rect {
+    let rect = bounding_client_rect(element);
+    let scale = DomPlatform.get_scale(element);
+
+    Rect {
+        x: rect.x + f64::from(element.client_left()) * scale.x,
+        y: rect.y + f64::from(element.client_top()) * scale.y,
+        width: f64::from(element.client_width()) * scale.x,
+        height: f64::from(element.client_height()) * scale.y,
+    }
+}
+
+fn css_position(element: &Element) -> String {
+    computed_style(element)
+        .and_then(|style| style.get_property_value("position").ok())
+        .unwrap_or_default()
+}
+
+/// Whether an ancestor between the element and `stop` is `position: fixed`
+fn has_fixed_position_ancestor(element: &Element, stop: &Element) -> bool {
+    let mut current = parent_element(element);
+
+    while let Some(ancestor) = current {
+        if &ancestor == stop || is_last_traversable(&ancestor) {
+            return false;
+        }
+        if css_position(&ancestor) == "fixed" {
+            return true;
+        }
+        current = parent_element(&ancestor);
+    }
+
+    false
+}
+
+/// Whether the walk up the ancestors stops at the element, which is `<html>` or `<body>`
+fn is_last_traversable(element: &Element) -> bool {
+    let name = element.node_name();
+    name.eq_ignore_ascii_case("html") || name.eq_ignore_ascii_case("body")
+}
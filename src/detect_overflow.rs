@@ -0,0 +1,146 @@
+use crate::{MiddlewareState, Rect, SideObject};
+
+/// Element whose overflow is measured
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ElementContext {
+    Reference,
+    #[default]
+    Floating,
+}
+
+/// Boundary the element is checked against, in addition to the root boundary
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Boundary {
+    /// The ancestors that clip the element
+    #[default]
+    ClippingAncestors,
+    /// A custom rect, in viewport coordinates
+    Rect(Rect),
+}
+
+/// Outermost boundary the element is checked against
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RootBoundary {
+    #[default]
+    Viewport,
+    /// A custom rect, in viewport coordinates
+    Rect(Rect),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DetectOverflowOptions {
+    pub boundary: Boundary,
+    pub root_boundary: RootBoundary,
+    /// Element whose overflow is measured
+    pub element_context: ElementContext,
+    /// Check against the clipping boundary of the other element, e.g. whether the floating
+    /// element left the area that clips the reference
+    pub alt_boundary: bool,
+    /// Shrinks the clipping rect on every side
+    pub padding: f64,
+}
+
+/// Measure how far an element overflows its clipping boundary on each side
+///
+/// Positive values mean the element overflows that side by that many pixels, negative values mean
+/// there is that much space left. This is the primitive behind [`flip`](crate::flip),
+/// [`shift`](crate::shift), [`size`](crate::size) and [`hide`](crate::hide), and can be used to
+/// write custom middleware.
+pub fn detect_overflow(state: &MiddlewareState, options: DetectOverflowOptions) -> SideObject {
+    let element = match options.element_context {
+        ElementContext::Reference => state.rects.reference,
+        ElementContext::Floating => Rect {
+            x: state.x,
+            y: state.y,
+            ..state.rects.floating
+        },
+    };
+
+    // Each element is clipped by its own ancestors, `alt_boundary` swaps in the other element's
+    let clipping_rect = match (options.element_context, options.alt_boundary) {
+        (ElementContext::Floating, false) | (ElementContext::Reference, true) => {
+            state.clipping_rect
+        }
+        (ElementContext::Reference, false) | (ElementContext::Floating, true) => {
+            state.reference_clipping_rect
+        }
+    };
+    let boundary = match options.boundary {
+        Boundary::ClippingAncestors => clipping_rect,
+        Boundary::Rect(rect) => rect,
+    };
+    let root_boundary = match options.root_boundary {
+        RootBoundary::Viewport => state.viewport,
+        RootBoundary::Rect(rect) => rect,
+    };
+
+    detect_overflow_from_rect(
+        element,
+        boundary.intersection(&root_boundary),
+        options.padding,
+    )
+}
+
+/// Measure how far `element` overflows `clipping_rect` on each side (testable without DOM)
+pub fn detect_overflow_from_rect(element: Rect, clipping_rect: Rect, padding: f64) -> SideObject {
+    SideObject {
+        top: clipping_rect.y - element.y + padding,
+        right: element.right() - clipping_rect.right() + padding,
+        bottom: element.bottom() - clipping_rect.bottom() + padding,
+        left: clipping_rect.x - element.x + padding,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow_is_negative_when_element_fits() {
+        let element = Rect {
+            x: 10.0,
+            y: 20.0,
+            width: 50.0,
+            height: 30.0,
+        };
+        let clipping_rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        };
+
+        let overflow = detect_overflow_from_rect(element, clipping_rect, 0.0);
+
+        assert_eq!(
+            overflow,
+            SideObject {
+                top: -20.0,
+                right: -40.0,
+                bottom: -50.0,
+                left: -10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn overflow_is_positive_past_the_edge() {
+        let element = Rect {
+            x: 80.0,
+            y: 90.0,
+            width: 50.0,
+            height: 30.0,
+        };
+        let clipping_rect = Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        };
+
+        let overflow = detect_overflow_from_rect(element, clipping_rect, 5.0);
+
+        assert_eq!(overflow.right, 35.0);
+        assert_eq!(overflow.bottom, 25.0);
+    }
+}
@@ -0,0 +1,112 @@
+use leptos::prelude::window;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::{CssStyleDeclaration, Element, ShadowRoot};
+
+use crate::Rect;
+
+/// Computed style of an element
+pub(crate) fn computed_style(element: &Element) -> Option<CssStyleDeclaration> {
+    window().get_computed_style(element).ok().flatten()
+}
+
+/// Parent element in the composed tree, stepping out of shadow roots to their host
+pub(crate) fn parent_element(element: &Element) -> Option<Element> {
+    match element.parent_node()?.dyn_into::<ShadowRoot>() {
+        Ok(shadow_root) => Some(shadow_root.host()),
+        Err(node) => node.dyn_into::<Element>().ok(),
+    }
+}
+
+/// Whether the element scrolls or clips its content
+pub(crate) fn is_overflow_element(element: &Element) -> bool {
+    let Some(style) = computed_style(element) else {
+        return false;
+    };
+    let property = |name| style.get_property_value(name).unwrap_or_default();
+
+    let overflow = [
+        property("overflow"),
+        property("overflow-x"),
+        property("overflow-y"),
+    ]
+    .concat();
+    let display = property("display");
+
+    ["auto", "scroll", "overlay", "hidden", "clip"]
+        .iter()
+        .any(|value| overflow.contains(value))
+        && !matches!(display.as_str(), "inline" | "contents")
+}
+
+/// Whether the element is the containing block of its `position: fixed` and `position: absolute`
+/// descendants without being positioned itself, e.g. because it is transformed
+pub(crate) fn is_containing_block(element: &Element) -> bool {
+    let Some(style) = computed_style(element) else {
+        return false;
+    };
+    let property = |name| style.get_property_value(name).unwrap_or_default();
+    let is_set = |name, initial| {
+        let value = property(name);
+        !value.is_empty() && value != initial
+    };
+
+    let will_change = property("will-change");
+    let contain = property("contain");
+
+    [
+        "transform",
+        "translate",
+        "scale",
+        "rotate",
+        "perspective",
+        "filter",
+        "backdrop-filter",
+    ]
+    .iter()
+    .any(|name| is_set(name, "none"))
+        || is_set("container-type", "normal")
+        || [
+            "transform",
+            "translate",
+            "scale",
+            "rotate",
+            "perspective",
+            "filter",
+        ]
+        .iter()
+        .any(|value| will_change.contains(value))
+        || ["paint", "layout", "strict", "content"]
+            .iter()
+            .any(|value| contain.contains(value))
+}
+
+/// Ancestors of the element that scroll or clip it, closest first
+///
+/// The document itself is not included, scrolling it is reported on the window.
+pub(crate) fn overflow_ancestors(element: &Element) -> Vec<Element> {
+    let mut ancestors = Vec::new();
+    let mut current = parent_element(element);
+
+    while let Some(ancestor) = current {
+        if ancestor.node_name().eq_ignore_ascii_case("html") {
+            break;
+        }
+        if is_overflow_element(&ancestor) {
+            ancestors.push(ancestor.clone());
+        }
+        current = parent_element(&ancestor);
+    }
+
+    ancestors
+}
+
+/// Bounding client rect of an element, relative to the viewport
+pub(crate) fn bounding_client_rect(element: &Element) -> Rect {
+    let rect = element.get_bounding_client_rect();
+    Rect {
+        x: rect.left(),
+        y: rect.top(),
+        width: rect.width(),
+        height: rect.height(),
+    }
+}
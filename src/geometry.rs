@@ -0,0 +1,160 @@
+use crate::Side;
+
+/// Position and size of an element, in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// Rect large enough to never clip, used when no boundary is known
+    pub(crate) const UNBOUNDED: Rect = Rect {
+        x: f64::MIN / 4.0,
+        y: f64::MIN / 4.0,
+        width: f64::MAX / 2.0,
+        height: f64::MAX / 2.0,
+    };
+
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// Area covered by both rects
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+
+        Rect {
+            x: left,
+            y: top,
+            width: self.right().min(other.right()) - left,
+            height: self.bottom().min(other.bottom()) - top,
+        }
+    }
+
+    /// Smallest rect containing all of `rects`
+    pub(crate) fn bounding(rects: &[Rect]) -> Option<Rect> {
+        let first = rects.first()?;
+        let (mut left, mut top) = (first.x, first.y);
+        let (mut right, mut bottom) = (first.right(), first.bottom());
+
+        for rect in &rects[1..] {
+            left = left.min(rect.x);
+            top = top.min(rect.y);
+            right = right.max(rect.right());
+            bottom = bottom.max(rect.bottom());
+        }
+
+        Some(Rect {
+            x: left,
+            y: top,
+            width: right - left,
+            height: bottom - top,
+        })
+    }
+}
+
+/// A point or a pair of per-axis factors, e.g. an element's scale
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coords {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Size of an element, in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dimensions {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Dimensions {
+    pub(crate) fn length(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.width,
+            Axis::Y => self.height,
+        }
+    }
+}
+
+/// Bounding rectangles of the reference and floating elements
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ElementRects {
+    pub reference: Rect,
+    pub floating: Rect,
+}
+
+/// A value for each side of a rect
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SideObject {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl SideObject {
+    pub fn get(&self, side: Side) -> f64 {
+        match side {
+            Side::Top => self.top,
+            Side::Right => self.right,
+            Side::Bottom => self.bottom,
+            Side::Left => self.left,
+        }
+    }
+}
+
+/// Horizontal or vertical axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    /// The axis the floating element is aligned along when placed on `side`
+    pub(crate) fn alignment(side: Side) -> Self {
+        match side {
+            Side::Top | Side::Bottom => Axis::X,
+            Side::Right | Side::Left => Axis::Y,
+        }
+    }
+
+    pub(crate) fn opposite(self) -> Self {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+
+    /// Sides at the start and end of this axis
+    pub(crate) fn sides(self) -> (Side, Side) {
+        match self {
+            Axis::X => (Side::Left, Side::Right),
+            Axis::Y => (Side::Top, Side::Bottom),
+        }
+    }
+}
+
+impl Rect {
+    pub(crate) fn start(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+        }
+    }
+
+    pub(crate) fn length(&self, axis: Axis) -> f64 {
+        match axis {
+            Axis::X => self.width,
+            Axis::Y => self.height,
+        }
+    }
+}
@@ -1,4 +1,36 @@
+use std::rc::Rc;
+
 use leptos::prelude::*;
+use leptos::tachys::html::element::ElementType;
+use leptos::wasm_bindgen::JsCast;
+
+mod auto_update;
+mod clipping;
+mod detect_overflow;
+mod dom;
+mod geometry;
+mod middleware;
+mod platform;
+mod reference;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use auto_update::{AutoUpdateCleanup, AutoUpdateOptions, auto_update};
+pub use clipping::{clipping_ancestors, get_clipping_rect};
+pub use detect_overflow::{
+    Boundary, DetectOverflowOptions, ElementContext, RootBoundary, detect_overflow,
+    detect_overflow_from_rect,
+};
+pub use geometry::{Coords, Dimensions, ElementRects, Rect, SideObject};
+pub use middleware::{
+    ArrowData, ArrowElement, AutoPlacementData, AutoPlacementOptions, FallbackStrategy, FlipData,
+    FlipOptions, HideData, HideOptions, HideStrategy, InlineOptions, LimitShift, Middleware,
+    MiddlewareClone, MiddlewareData, MiddlewareReturn, MiddlewareState, PlacementOverflow, Reset,
+    ShiftData, ShiftOptions, SizeApply, SizeData, SizeOptions, arrow, auto_placement, flip, hide,
+    inline, shift, size,
+};
+pub use platform::{DomPlatform, MiddlewarePlatform, Platform};
+pub use reference::{BoundingClientRect, ClientRects, IntoReference, Reference, VirtualElement};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Side {
@@ -8,6 +40,18 @@ pub enum Side {
     Left,
 }
 
+impl Side {
+    /// The side on the other end of the same axis
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Right => Side::Left,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Align {
     Start,
@@ -15,12 +59,78 @@ pub enum Align {
     End,
 }
 
+impl Align {
+    /// The alignment on the other end of the cross-axis, `Center` stays centered
+    pub fn opposite(self) -> Self {
+        match self {
+            Align::Start => Align::End,
+            Align::Center => Align::Center,
+            Align::End => Align::Start,
+        }
+    }
+}
+
+/// A side of the reference element combined with an alignment along it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placement {
+    pub side: Side,
+    pub align: Align,
+}
+
+impl Placement {
+    /// The placement on the opposite side, keeping the alignment
+    pub fn opposite(self) -> Self {
+        Self {
+            side: self.side.opposite(),
+            align: self.align,
+        }
+    }
+}
+
+/// CSS `position` the floating element is placed with
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Strategy {
+    /// Coordinates are relative to the offset parent, including its scroll offset and borders
+    #[default]
+    Absolute,
+    /// Coordinates are relative to the viewport
+    Fixed,
+}
+
+impl Strategy {
+    /// The matching CSS `position` value
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Strategy::Absolute => "absolute",
+            Strategy::Fixed => "fixed",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatingOptions {
     pub side: Side,
     pub align: Align,
     pub side_offset: f64,
     pub align_offset: f64,
+    /// Middleware run in order after the base position is computed
+    pub middleware: Vec<Box<dyn Middleware>>,
+    /// Keep the position up to date while both elements are mounted, see [`auto_update`]
+    pub auto_update: Option<AutoUpdateOptions>,
+    /// CSS `position` of the floating element, which the coordinates are relative to
+    pub strategy: Strategy,
+    /// Place the element with `transform: translate(...)` in
+    /// [`UseFloatingReturn::floating_styles`] instead of `top` and `left`
+    pub transform: bool,
+    /// Snap the coordinates to device pixels, reported by [`Platform::get_device_pixel_ratio`], so
+    /// the element renders crisply on high density screens
+    pub round_by_dpr: bool,
+    /// Whether the floating element is open, it is only positioned and kept up to date while open,
+    /// always open when `None`
+    pub open: Option<Signal<bool>>,
+    /// Called with the requested open state, e.g. by interaction hooks, through
+    /// [`UseFloatingReturn::on_open_change`]
+    pub on_open_change: Option<Callback<bool>>,
 }
 
 impl Default for FloatingOptions {
@@ -30,6 +140,13 @@ impl Default for FloatingOptions {
             align: Align::Start,
             side_offset: 0.0,
             align_offset: 0.0,
+            middleware: Vec::new(),
+            auto_update: None,
+            strategy: Strategy::Absolute,
+            transform: false,
+            round_by_dpr: true,
+            open: None,
+            on_open_change: None,
         }
     }
 }
@@ -41,6 +158,7 @@ pub struct FloatingPosition {
     pub y: f64,
     pub side: Side,
     pub align: Align,
+    pub middleware_data: MiddlewareData,
 }
 
 /// Returns dynamic positioning data for floating elements
@@ -49,6 +167,30 @@ pub struct UseFloatingReturn {
     pub y: RwSignal<f64>,
     pub side: Signal<Side>,
     pub align: Signal<Align>,
+    pub middleware_data: Signal<MiddlewareData>,
+    /// Arrow offset along the x-axis, set by [`arrow`] for `Side::Top` and `Side::Bottom`
+    pub arrow_x: Signal<Option<f64>>,
+    /// Arrow offset along the y-axis, set by [`arrow`] for `Side::Left` and `Side::Right`
+    pub arrow_y: Signal<Option<f64>>,
+    /// Width the floating element can grow to, set by [`size`]
+    pub available_width: Signal<Option<f64>>,
+    /// Height the floating element can grow to, set by [`size`]
+    pub available_height: Signal<Option<f64>>,
+    /// Whether the reference is fully clipped, set by [`hide`]
+    pub reference_hidden: Signal<bool>,
+    /// Whether the floating element escaped its boundary, set by [`hide`]
+    pub escaped: Signal<bool>,
+    /// CSS `position` the coordinates are computed for, see [`Strategy::as_str`]
+    pub strategy: Signal<Strategy>,
+    /// Inline styles placing the floating element, hidden until it is positioned
+    pub floating_styles: Signal<String>,
+    /// Whether `x` and `y` were computed for the current elements, rather than still being the
+    /// initial zero or the position of elements that were unmounted
+    pub is_positioned: Signal<bool>,
+    /// The `open` option
+    pub open: Signal<bool>,
+    /// Request a new open state, forwarded to the `on_open_change` option
+    pub on_open_change: Callback<bool>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -81,89 +223,336 @@ impl From<FloatingY> for f64 {
     }
 }
 
-pub fn use_floating(
-    reference_ref: NodeRef<leptos::html::Button>,
-    floating_ref: NodeRef<leptos::html::Div>,
-    options: FloatingOptions,
-) -> UseFloatingReturn {
+/// Position `floating_ref` next to `reference`, a `NodeRef` or a [`VirtualElement`]
+///
+/// Both `NodeRef`s can point to any HTML or SVG element. `options` can be a plain
+/// [`FloatingOptions`] or a signal, the position is recalculated whenever it changes.
+pub fn use_floating<F>(
+    reference: impl IntoReference,
+    floating_ref: NodeRef<F>,
+    options: impl Into<Signal<FloatingOptions>>,
+) -> UseFloatingReturn
+where
+    F: ElementType,
+    F::Output: JsCast + Clone + Into<leptos::web_sys::Element> + 'static,
+{
+    use leptos::web_sys::Element;
+
+    use_floating_with_platform(
+        DomPlatform,
+        reference.into_reference(),
+        move || floating_ref.get().map(Into::<Element>::into),
+        options,
+    )
+}
+
+/// Like [`use_floating`], measuring the elements returned by `reference` and `floating` through
+/// `platform`
+///
+/// Both closures are tracked, so the position is recalculated whenever a signal they read changes,
+/// as are the closures of a [`VirtualElement`] reference and the options.
+pub fn use_floating_with_platform<P, R>(
+    platform: P,
+    reference: impl Fn() -> Option<R> + 'static,
+    floating: impl Fn() -> Option<P::Element> + 'static,
+    options: impl Into<Signal<FloatingOptions>>,
+) -> UseFloatingReturn
+where
+    P: Platform + 'static,
+    R: Into<Reference<P::Element>>,
+{
+    let options = options.into();
+    let (initial_side, initial_align) =
+        options.with_untracked(|options| (options.side, options.align));
+
     let x = RwSignal::new(FloatingX::default().into());
     let y = RwSignal::new(FloatingY::default().into());
-    let side = RwSignal::new(options.side);
-    let align = RwSignal::new(options.align);
+    let side = RwSignal::new(initial_side);
+    let align = RwSignal::new(initial_align);
+    let middleware_data = RwSignal::new(MiddlewareData::default());
+    let is_positioned = RwSignal::new(false);
+    let open =
+        Memo::new(move |_| options.with(|options| options.open.is_none_or(|open| open.get())));
+
+    let platform = Rc::new(platform);
+    let reference = Rc::new(move || reference().map(Into::into));
+    let elements = Rc::new(move || reference().zip(floating()));
+    let update: Rc<dyn Fn()> = Rc::new({
+        let platform = Rc::clone(&platform);
+        let elements = Rc::clone(&elements);
+
+        move || {
+            // Read untracked since updates also run outside the reactive graph
+            if open.get_untracked()
+                && let Some((reference, floating)) = untrack(|| elements())
+                && let Some(position) = calculate_position_with_platform(
+                    &*platform,
+                    reference,
+                    &floating,
+                    options.get_untracked(),
+                )
+            {
+                x.set(position.x);
+                y.set(position.y);
+                side.set(position.side);
+                align.set(position.align);
+                middleware_data.set(position.middleware_data);
+                is_positioned.set(true);
+            }
+        }
+    });
 
-    // Calculate position whenever elements change
+    // Calculate position whenever the elements, options or open state change, a virtual reference
+    // is measured by its closures so the signals they read are tracked as well
     Effect::new({
-        let options = options.clone();
+        let platform = Rc::clone(&platform);
+        let elements = Rc::clone(&elements);
+        let update = Rc::clone(&update);
 
         move |_| {
-            // Track NodeRefs so effect re-runs when they get populated
-            let _ = (reference_ref.get(), floating_ref.get());
-
-            request_animation_frame({
-                let x = x;
-                let y = y;
-                let side = side;
-                let align = align;
-                let options = options.clone();
-
-                move || {
-                    // Use untracked access inside the animation frame to avoid context warnings
-                    if let (Some(reference), Some(floating)) =
-                        (reference_ref.get_untracked(), floating_ref.get_untracked())
-                        && let Some(position) = calculate_position(&reference, &floating, options)
-                    {
-                        x.set(position.x);
-                        y.set(position.y);
-                        side.set(position.side);
-                        align.set(position.align);
-                    }
+            options.track();
+            match elements().filter(|_| open.get()) {
+                Some((Reference::Virtual(virtual_element), _)) => {
+                    virtual_element.bounding_client_rect();
+                    virtual_element.client_rects();
                 }
+                Some(_) => {}
+                // The last position belongs to elements that are gone or closed
+                None => return is_positioned.set(false),
+            }
+
+            let update = Rc::clone(&update);
+            platform.schedule_update(Box::new(move || update()));
+        }
+    });
+
+    // Keep listeners registered while both elements are mounted and open, only re-registering them
+    // when the elements, the open state or the auto update options change
+    let auto_update_options = Memo::new(move |_| options.with(|options| options.auto_update));
+    let auto_update_cleanup = StoredValue::new_local(None::<AutoUpdateCleanup>);
+    Effect::new(move |_| {
+        let elements = elements().filter(|_| open.get());
+
+        // Listeners registered for the previous elements are removed on drop
+        auto_update_cleanup.set_value(None);
+        if let (Some((reference, floating)), Some(auto_update_options)) =
+            (&elements, auto_update_options.get())
+        {
+            // Registering may measure a virtual reference, which must not subscribe this effect
+            let cleanup = untrack(|| {
+                platform.auto_update(reference, floating, Rc::clone(&update), auto_update_options)
             });
+            auto_update_cleanup.set_value(cleanup);
         }
     });
+    on_cleanup(move || {
+        auto_update_cleanup.try_set_value(None);
+    });
 
     UseFloatingReturn {
         x,
         y,
         side: side.into(),
         align: align.into(),
+        middleware_data: middleware_data.into(),
+        arrow_x: Signal::derive(move || {
+            middleware_data.with(|data| data.get::<ArrowData>().and_then(|arrow| arrow.x))
+        }),
+        arrow_y: Signal::derive(move || {
+            middleware_data.with(|data| data.get::<ArrowData>().and_then(|arrow| arrow.y))
+        }),
+        available_width: Signal::derive(move || {
+            middleware_data.with(|data| data.get::<SizeData>().map(|size| size.available_width))
+        }),
+        available_height: Signal::derive(move || {
+            middleware_data.with(|data| data.get::<SizeData>().map(|size| size.available_height))
+        }),
+        reference_hidden: Signal::derive(move || {
+            middleware_data.with(|data| {
+                data.get::<HideData>()
+                    .and_then(|hide| hide.reference_hidden)
+                    .unwrap_or(false)
+            })
+        }),
+        escaped: Signal::derive(move || {
+            middleware_data.with(|data| {
+                data.get::<HideData>()
+                    .and_then(|hide| hide.escaped)
+                    .unwrap_or(false)
+            })
+        }),
+        strategy: Signal::derive(move || options.with(|options| options.strategy)),
+        floating_styles: Signal::derive(move || {
+            let (strategy, transform) =
+                options.with(|options| (options.strategy, options.transform));
+            floating_styles(strategy, x.get(), y.get(), transform, is_positioned.get())
+        }),
+        is_positioned: is_positioned.into(),
+        open: open.into(),
+        on_open_change: Callback::new(move |open| {
+            if let Some(on_open_change) = options.with_untracked(|options| options.on_open_change) {
+                on_open_change.run(open);
+            }
+        }),
+    }
+}
+
+/// Inline styles for a floating element at `x`/`y`, hidden until it is positioned
+fn floating_styles(
+    strategy: Strategy,
+    x: f64,
+    y: f64,
+    transform: bool,
+    is_positioned: bool,
+) -> String {
+    let mut styles = format!("position: {};", strategy.as_str());
+    if transform {
+        styles.push_str(&format!(
+            " left: 0; top: 0; transform: translate({x}px, {y}px);"
+        ));
+    } else {
+        styles.push_str(&format!(" left: {x}px; top: {y}px;"));
+    }
+    if !is_positioned {
+        styles.push_str(" visibility: hidden;");
     }
+    styles
 }
 
 /// Calculate the optimal position for a floating element
-pub fn calculate_position<T, U>(
-    reference: &T,
+///
+/// The reference is an element or a [`VirtualElement`].
+pub fn calculate_position<U>(
+    reference: impl Into<Reference<leptos::web_sys::Element>>,
     floating: &U,
     options: FloatingOptions,
 ) -> Option<FloatingPosition>
 where
-    T: AsRef<leptos::web_sys::HtmlElement>,
     U: AsRef<leptos::web_sys::HtmlElement>,
 {
-    use leptos::wasm_bindgen::JsCast;
-    use leptos::web_sys::*;
-
-    // Get the bounding rectangle of the reference element
-    let reference_element: &HtmlElement = reference.as_ref();
-    let ref_rect = reference_element
-        .unchecked_ref::<Element>()
-        .get_bounding_client_rect();
-
-    // Get the bounding rectangle of the floating element
-    let floating_element: &HtmlElement = floating.as_ref();
-    let float_rect = floating_element
-        .unchecked_ref::<Element>()
-        .get_bounding_client_rect();
-
-    calculate_position_from_rect(
-        ref_rect.left(),
-        ref_rect.top(),
-        ref_rect.width(),
-        ref_rect.height(),
-        float_rect.width(),
-        float_rect.height(),
-        options,
-    )
+    let floating: &leptos::web_sys::HtmlElement = floating.as_ref();
+
+    calculate_position_with_platform(&DomPlatform, reference, floating, options)
+}
+
+/// Calculate the position of a floating element next to a real or virtual reference, measuring
+/// the elements through `platform`
+pub fn calculate_position_with_platform<P: Platform>(
+    platform: &P,
+    reference: impl Into<Reference<P::Element>>,
+    floating: &P::Element,
+    options: FloatingOptions,
+) -> Option<FloatingPosition> {
+    match reference.into() {
+        Reference::Element(reference) => {
+            let rects = platform.get_element_rects(&reference, floating);
+            // An inline reference that wraps across lines has one client rect per line
+            let reference_client_rects = platform.get_client_rects(&reference);
+
+            calculate_position_from_measurements(
+                platform,
+                rects,
+                reference_client_rects,
+                Some(&reference),
+                floating,
+                options,
+            )
+        }
+        Reference::Virtual(reference) => {
+            let floating_size = platform.get_dimensions(floating);
+            let rects = ElementRects {
+                reference: reference.bounding_client_rect(),
+                floating: Rect {
+                    x: 0.0,
+                    y: 0.0,
+                    width: floating_size.width,
+                    height: floating_size.height,
+                },
+            };
+
+            calculate_position_from_measurements(
+                platform,
+                rects,
+                reference.client_rects(),
+                None,
+                floating,
+                options,
+            )
+        }
+    }
+}
+
+/// Run the pipeline over measured rects, clipped by the clipping ancestors of each element
+///
+/// A virtual reference has no element to measure, it is only clipped by the viewport.
+fn calculate_position_from_measurements<P: Platform>(
+    platform: &P,
+    rects: ElementRects,
+    reference_client_rects: Vec<Rect>,
+    reference: Option<&P::Element>,
+    floating: &P::Element,
+    options: FloatingOptions,
+) -> Option<FloatingPosition> {
+    // The ancestors are measured without the viewport, so middleware can swap in another root
+    let ancestors_rect = |element| {
+        platform.get_clipping_rect(
+            element,
+            &Boundary::ClippingAncestors,
+            &RootBoundary::Rect(Rect::UNBOUNDED),
+        )
+    };
+    let clipping = ClippingRects {
+        floating: ancestors_rect(floating),
+        reference: reference.map_or(Rect::UNBOUNDED, ancestors_rect),
+        viewport: platform.get_clipping_rect(
+            floating,
+            &Boundary::Rect(Rect::UNBOUNDED),
+            &RootBoundary::Viewport,
+        ),
+    };
+    let rtl = platform.is_rtl(floating);
+
+    // The pipeline works in viewport coordinates, which are converted for the strategy afterwards
+    let mut position = compute_position(
+        platform,
+        rects,
+        reference_client_rects,
+        clipping,
+        rtl,
+        &options,
+    );
+
+    let offset_parent = platform.get_offset_parent(floating, options.strategy);
+    // Where the offset parent's origin and a unit square in its coordinates end up in the viewport
+    let origin = platform.convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+        },
+        offset_parent.as_ref(),
+        options.strategy,
+    );
+    position.x = (position.x - origin.x) / origin.width;
+    position.y = (position.y - origin.y) / origin.height;
+
+    if options.round_by_dpr {
+        let ratio = platform.get_device_pixel_ratio();
+        position.x = round_by_dpr(position.x, ratio);
+        position.y = round_by_dpr(position.y, ratio);
+    }
+
+    Some(position)
+}
+
+/// Snap a CSS pixel value to the closest device pixel
+fn round_by_dpr(value: f64, device_pixel_ratio: f64) -> f64 {
+    if device_pixel_ratio.is_finite() && device_pixel_ratio > 0.0 {
+        (value * device_pixel_ratio).round() / device_pixel_ratio
+    } else {
+        value.round()
+    }
 }
 
 /// Calculate position from bounding rectangle values (testable without DOM)
@@ -176,61 +565,239 @@ pub fn calculate_position_from_rect(
     floating_height: f64,
     options: FloatingOptions,
 ) -> Option<FloatingPosition> {
+    let rects = ElementRects {
+        reference: Rect {
+            x: reference_x,
+            y: reference_y,
+            width: reference_width,
+            height: reference_height,
+        },
+        floating: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: floating_width,
+            height: floating_height,
+        },
+    };
+
+    Some(compute_position(
+        &DomPlatform,
+        rects,
+        Vec::new(),
+        ClippingRects {
+            floating: Rect::UNBOUNDED,
+            reference: Rect::UNBOUNDED,
+            viewport: Rect::UNBOUNDED,
+        },
+        false,
+        &options,
+    ))
+}
+
+/// Calculate position from element rects, clipped by `clipping_rect` (testable without DOM)
+///
+/// Unlike [`calculate_position_from_rect`], middleware that detect overflow such as [`flip`] see
+/// the given boundary instead of an unbounded one.
+pub fn calculate_position_from_rects(
+    rects: ElementRects,
+    clipping_rect: Rect,
+    options: FloatingOptions,
+) -> Option<FloatingPosition> {
+    Some(compute_position(
+        &DomPlatform,
+        rects,
+        Vec::new(),
+        ClippingRects {
+            floating: Rect::UNBOUNDED,
+            reference: Rect::UNBOUNDED,
+            viewport: clipping_rect,
+        },
+        false,
+        &options,
+    ))
+}
+
+/// Boundaries the middleware check the elements against, see [`MiddlewareState`]
+struct ClippingRects {
+    floating: Rect,
+    reference: Rect,
+    viewport: Rect,
+}
+
+/// Compute the base position for the requested side and run the middleware pipeline over it
+///
+/// Middleware measure elements of their own, such as the arrow, through `platform`.
+fn compute_position(
+    platform: &dyn MiddlewarePlatform,
+    rects: ElementRects,
+    reference_client_rects: Vec<Rect>,
+    clipping: ClippingRects,
+    rtl: bool,
+    options: &FloatingOptions,
+) -> FloatingPosition {
+    let (x, y) = compute_coords(
+        &rects,
+        options.side,
+        options.align,
+        rtl,
+        options.side_offset,
+        options.align_offset,
+    );
+
+    let mut state = MiddlewareState {
+        x,
+        y,
+        side: options.side,
+        align: options.align,
+        initial_side: options.side,
+        initial_align: options.align,
+        side_offset: options.side_offset,
+        align_offset: options.align_offset,
+        rects,
+        rtl,
+        reference_client_rects,
+        clipping_rect: clipping.floating,
+        reference_clipping_rect: clipping.reference,
+        viewport: clipping.viewport,
+        middleware_data: MiddlewareData::default(),
+        platform,
+    };
+
+    let mut reset_count = 0;
+    let mut index = 0;
+
+    while let Some(middleware) = options.middleware.get(index) {
+        let result = middleware.compute(&state);
+
+        state.x = result.x.unwrap_or(state.x);
+        state.y = result.y.unwrap_or(state.y);
+        state.middleware_data.merge(result.data);
+
+        if let Some(reset) = result.reset
+            && reset_count < middleware::MAX_RESETS
+        {
+            reset_count += 1;
+
+            state.side = reset.side.unwrap_or(state.side);
+            state.align = reset.align.unwrap_or(state.align);
+            state.rects = reset.rects.unwrap_or(state.rects);
+
+            (state.x, state.y) = compute_coords(
+                &state.rects,
+                state.side,
+                state.align,
+                rtl,
+                options.side_offset,
+                options.align_offset,
+            );
+            index = 0;
+            continue;
+        }
+
+        index += 1;
+    }
+
+    FloatingPosition {
+        x: state.x,
+        y: state.y,
+        side: state.side,
+        align: state.align,
+        middleware_data: state.middleware_data,
+    }
+}
+
+/// Compute the coordinates for a side and alignment, before any middleware runs
+pub(crate) fn compute_coords(
+    rects: &ElementRects,
+    side: Side,
+    align: Align,
+    rtl: bool,
+    side_offset: f64,
+    align_offset: f64,
+) -> (f64, f64) {
+    let reference = rects.reference;
+    let floating = rects.floating;
+
     // Determine whether the cross-axis is horizontal (x) or vertical (y)
-    let is_horizontal_side = matches!(options.side, Side::Top | Side::Bottom);
+    let is_horizontal_side = matches!(side, Side::Top | Side::Bottom);
+
+    // Start and end follow the text direction on a horizontal cross-axis
+    let align = if rtl && is_horizontal_side {
+        align.opposite()
+    } else {
+        align
+    };
 
     // Calculate base position based on side
-    let (base_x, base_y) = match options.side {
-        Side::Top => (reference_x, reference_y - options.side_offset),
-        Side::Right => (
-            reference_x + reference_width + options.side_offset,
-            reference_y,
-        ),
-        Side::Bottom => (
-            reference_x,
-            reference_y + reference_height + options.side_offset,
-        ),
-        Side::Left => (reference_x - options.side_offset, reference_y),
+    let (base_x, base_y) = match side {
+        Side::Top => (reference.x, reference.y - floating.height - side_offset),
+        Side::Right => (reference.x + reference.width + side_offset, reference.y),
+        Side::Bottom => (reference.x, reference.y + reference.height + side_offset),
+        Side::Left => (reference.x - floating.width - side_offset, reference.y),
     };
 
     // Calculate alignment shift on the cross-axis
-    let align_shift = match options.align {
+    let align_shift = match align {
         Align::Start => 0.0,
         Align::Center => {
             if is_horizontal_side {
-                (reference_width - floating_width) / 2.0
+                (reference.width - floating.width) / 2.0
             } else {
-                (reference_height - floating_height) / 2.0
+                (reference.height - floating.height) / 2.0
             }
         }
         Align::End => {
             if is_horizontal_side {
-                reference_width - floating_width
+                reference.width - floating.width
             } else {
-                reference_height - floating_height
+                reference.height - floating.height
             }
         }
     };
 
     // Apply alignment shift and align_offset on the cross-axis
-    let (x, y) = if is_horizontal_side {
-        (base_x + align_shift + options.align_offset, base_y)
+    if is_horizontal_side {
+        (base_x + align_shift + align_offset, base_y)
     } else {
-        (base_x, base_y + align_shift + options.align_offset)
-    };
-
-    Some(FloatingPosition {
-        x,
-        y,
-        side: options.side,
-        align: options.align,
-    })
+        (base_x, base_y + align_shift + align_offset)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn floating_styles_place_element_with_top_and_left() {
+        assert_eq!(
+            floating_styles(Strategy::Absolute, 100.0, 240.5, false, true),
+            "position: absolute; left: 100px; top: 240.5px;"
+        );
+    }
+
+    #[test]
+    fn floating_styles_place_element_with_transform() {
+        assert_eq!(
+            floating_styles(Strategy::Fixed, 100.0, 240.0, true, true),
+            "position: fixed; left: 0; top: 0; transform: translate(100px, 240px);"
+        );
+    }
+
+    #[test]
+    fn floating_styles_hide_element_until_positioned() {
+        assert_eq!(
+            floating_styles(Strategy::Absolute, 0.0, 0.0, false, false),
+            "position: absolute; left: 0px; top: 0px; visibility: hidden;"
+        );
+    }
+
+    #[test]
+    fn round_by_dpr_snaps_to_device_pixels() {
+        assert_eq!(round_by_dpr(10.3, 1.0), 10.0);
+        assert_eq!(round_by_dpr(10.3, 2.0), 10.5);
+        assert_eq!(round_by_dpr(10.3, 0.0), 10.0);
+    }
+
     #[test]
     fn floating_options_default_values() {
         let options = FloatingOptions::default();
@@ -247,6 +814,7 @@ mod tests {
             align: Align::Center,
             side_offset: 8.0,
             align_offset: 4.0,
+            ..Default::default()
         };
         assert_eq!(options.side, Side::Top);
         assert_eq!(options.align, Align::Center);
@@ -282,6 +850,7 @@ mod tests {
             align: Align::End,
             side_offset: 10.0,
             align_offset: 5.0,
+            ..Default::default()
         };
 
         let cloned = original.clone();
@@ -298,6 +867,7 @@ mod tests {
             y: 200.0,
             side: Side::Bottom,
             align: Align::Center,
+            middleware_data: MiddlewareData::default(),
         };
 
         assert_eq!(position.x, 100.0);
@@ -361,6 +931,7 @@ mod tests {
             align: Align::Start,
             side_offset: -5.0,
             align_offset: -10.0,
+            ..Default::default()
         };
 
         assert_eq!(options.side_offset, -5.0);
@@ -374,6 +945,7 @@ mod tests {
             align: Align::Start,
             side_offset: 0.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         assert_eq!(options.side_offset, 0.0);
@@ -387,6 +959,7 @@ mod tests {
             y: 250.0,
             side: Side::Right,
             align: Align::End,
+            middleware_data: MiddlewareData::default(),
         };
 
         let cloned = original.clone();
@@ -403,6 +976,7 @@ mod tests {
             align: Align::Start,
             side_offset: 4.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
@@ -421,13 +995,14 @@ mod tests {
             align: Align::Start,
             side_offset: 8.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
             calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
         assert_eq!(position.x, 100.0);
-        assert_eq!(position.y, 192.0);
+        assert_eq!(position.y, 162.0);
         assert_eq!(position.side, Side::Top);
     }
 
@@ -438,6 +1013,7 @@ mod tests {
             align: Align::Start,
             side_offset: 12.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
@@ -455,12 +1031,13 @@ mod tests {
             align: Align::Start,
             side_offset: 6.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
             calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
-        assert_eq!(position.x, 94.0);
+        assert_eq!(position.x, 14.0);
         assert_eq!(position.y, 200.0);
         assert_eq!(position.side, Side::Left);
     }
@@ -472,6 +1049,7 @@ mod tests {
             align: Align::Start,
             side_offset: 0.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
@@ -488,13 +1066,14 @@ mod tests {
             align: Align::Start,
             side_offset: -10.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
             calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
         assert_eq!(position.x, 100.0);
-        assert_eq!(position.y, 210.0);
+        assert_eq!(position.y, 180.0);
     }
 
     #[test]
@@ -504,6 +1083,7 @@ mod tests {
             align: Align::Start,
             side_offset: 1000.0,
             align_offset: 0.0,
+            ..Default::default()
         };
 
         let position =
@@ -0,0 +1,202 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{Align, ElementRects, MiddlewarePlatform, Placement, Rect, Side};
+
+mod arrow;
+mod auto_placement;
+mod flip;
+mod hide;
+mod inline;
+mod shift;
+mod size;
+
+pub use arrow::{ArrowData, ArrowElement, arrow};
+pub use auto_placement::{AutoPlacementData, AutoPlacementOptions, auto_placement};
+pub use flip::{FallbackStrategy, FlipData, FlipOptions, PlacementOverflow, flip};
+pub use hide::{HideData, HideOptions, HideStrategy, hide};
+pub use inline::{InlineOptions, inline};
+pub use shift::{LimitShift, ShiftData, ShiftOptions, shift};
+pub use size::{SizeApply, SizeData, SizeOptions, size};
+
+/// Upper bound on pipeline restarts, guarding against middleware that keep requesting resets
+pub(crate) const MAX_RESETS: usize = 50;
+
+/// A step in the positioning pipeline that can modify the computed coordinates
+///
+/// Middleware run in the order they appear in
+/// [`FloatingOptions::middleware`](crate::FloatingOptions), each one receiving the state produced
+/// by the previous one.
+pub trait Middleware: MiddlewareClone + Send + Sync {
+    /// Name used to identify the middleware in debug output
+    fn name(&self) -> &'static str;
+
+    /// Compute the changes this middleware applies to the current state
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn;
+}
+
+/// Object-safe cloning for boxed middleware, implemented for every `Clone` middleware
+pub trait MiddlewareClone {
+    fn clone_box(&self) -> Box<dyn Middleware>;
+}
+
+impl<T> MiddlewareClone for T
+where
+    T: Middleware + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn Middleware> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Middleware> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl fmt::Debug for dyn Middleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Middleware").field(&self.name()).finish()
+    }
+}
+
+/// State shared between middleware while the pipeline runs
+#[derive(Debug, Clone)]
+pub struct MiddlewareState<'a> {
+    pub x: f64,
+    pub y: f64,
+    /// Side currently in use, which may differ from the requested one after a reset
+    pub side: Side,
+    pub align: Align,
+    /// Side requested through [`FloatingOptions`](crate::FloatingOptions)
+    pub initial_side: Side,
+    pub initial_align: Align,
+    pub side_offset: f64,
+    pub align_offset: f64,
+    pub rects: ElementRects,
+    /// Whether the floating element is laid out right to left, which mirrors `Start` and `End`
+    /// along the horizontal axis
+    pub rtl: bool,
+    /// Boxes of the reference element, one per line when an inline element wraps, empty when
+    /// the reference was not measured in the DOM
+    pub reference_client_rects: Vec<Rect>,
+    /// Intersection of the floating element's clipping ancestors, unbounded when it has none
+    pub clipping_rect: Rect,
+    /// Intersection of the reference element's clipping ancestors, unbounded when it has none
+    pub reference_clipping_rect: Rect,
+    /// Root boundary both elements are clipped by
+    pub viewport: Rect,
+    pub middleware_data: MiddlewareData,
+    /// Platform the elements are measured through, for middleware that measure elements of
+    /// their own
+    pub platform: &'a dyn MiddlewarePlatform,
+}
+
+/// Changes a middleware applies to the pipeline state
+#[derive(Debug, Clone, Default)]
+pub struct MiddlewareReturn {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    /// Data merged into [`MiddlewareState::middleware_data`]
+    pub data: MiddlewareData,
+    /// Restart the pipeline from the first middleware
+    pub reset: Option<Reset>,
+}
+
+/// Request to restart the pipeline, optionally with a different placement or rects
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Reset {
+    pub side: Option<Side>,
+    pub align: Option<Align>,
+    pub rects: Option<ElementRects>,
+}
+
+impl Reset {
+    /// Restart the pipeline with the floating element at `placement`
+    pub fn to_placement(placement: Placement) -> Self {
+        Self {
+            side: Some(placement.side),
+            align: Some(placement.align),
+            ..Default::default()
+        }
+    }
+}
+
+/// Data produced by middleware, keyed by type
+///
+/// Each middleware stores its own data type so later middleware and `use_floating` callers can
+/// read it back with [`MiddlewareData::get`].
+#[derive(Clone, Default)]
+pub struct MiddlewareData {
+    entries: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl MiddlewareData {
+    /// Get the data stored for type `T`, if any
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.entries
+            .get(&TypeId::of::<T>())
+            .and_then(|data| data.downcast_ref::<T>())
+    }
+
+    /// Store data for type `T`, replacing any previous value
+    pub fn insert<T: Any + Send + Sync>(&mut self, data: T) {
+        self.entries.insert(TypeId::of::<T>(), Arc::new(data));
+    }
+
+    /// Builder-style variant of [`MiddlewareData::insert`]
+    pub fn with<T: Any + Send + Sync>(mut self, data: T) -> Self {
+        self.insert(data);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn merge(&mut self, other: MiddlewareData) {
+        self.entries.extend(other.entries);
+    }
+}
+
+impl fmt::Debug for MiddlewareData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MiddlewareData")
+            .field("entries", &self.entries.len())
+            .finish()
+    }
+}
+
+/// Sides of the boundary the floating element can overflow along its alignment axis
+pub(crate) fn alignment_sides(
+    placement: Placement,
+    rects: &ElementRects,
+    rtl: bool,
+) -> (Side, Side) {
+    let is_horizontal_side = matches!(placement.side, Side::Top | Side::Bottom);
+    let start = if rtl { Align::End } else { Align::Start };
+
+    let main_alignment_side = match (is_horizontal_side, placement.align) {
+        (true, align) if align == start => Side::Right,
+        (true, _) => Side::Left,
+        (false, Align::Start) => Side::Bottom,
+        (false, _) => Side::Top,
+    };
+
+    let reference_is_longer = if is_horizontal_side {
+        rects.reference.width > rects.floating.width
+    } else {
+        rects.reference.height > rects.floating.height
+    };
+
+    let main_alignment_side = if reference_is_longer {
+        main_alignment_side.opposite()
+    } else {
+        main_alignment_side
+    };
+
+    (main_alignment_side, main_alignment_side.opposite())
+}
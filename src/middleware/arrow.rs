@@ -0,0 +1,152 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use leptos::tachys::html::element::ElementType;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::Element;
+
+use crate::geometry::Axis;
+use crate::{
+    Dimensions, Middleware, MiddlewareData, MiddlewarePlatform, MiddlewareReturn, MiddlewareState,
+};
+
+/// Element whose size determines where the arrow can be placed
+#[derive(Clone)]
+pub enum ArrowElement {
+    /// Measured through the [`Platform`](crate::Platform) in use each time the position is
+    /// computed, e.g. from a `NodeRef` to any HTML or SVG element, or an element of a custom
+    /// platform created with [`ArrowElement::element`]
+    Node(Arc<dyn Fn() -> Option<Box<dyn Any>> + Send + Sync>),
+    /// Fixed size, for arrows drawn with CSS or positioned without a DOM
+    Dimensions(Dimensions),
+}
+
+impl std::fmt::Debug for ArrowElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArrowElement::Node(_) => f.debug_tuple("Node").finish_non_exhaustive(),
+            ArrowElement::Dimensions(dimensions) => {
+                f.debug_tuple("Dimensions").field(dimensions).finish()
+            }
+        }
+    }
+}
+
+impl<E> From<NodeRef<E>> for ArrowElement
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + Into<Element> + 'static,
+{
+    fn from(node_ref: NodeRef<E>) -> Self {
+        ArrowElement::Node(Arc::new(move || {
+            node_ref.get_untracked().map(|element| {
+                let element: Element = element.into();
+                Box::new(element) as Box<dyn Any>
+            })
+        }))
+    }
+}
+
+impl From<Dimensions> for ArrowElement {
+    fn from(dimensions: Dimensions) -> Self {
+        ArrowElement::Dimensions(dimensions)
+    }
+}
+
+impl ArrowElement {
+    /// An element of any [`Platform`](crate::Platform), measured when it is an element of the
+    /// platform in use
+    pub fn element<E: Clone + Send + Sync + 'static>(element: E) -> Self {
+        ArrowElement::Node(Arc::new(move || {
+            Some(Box::new(element.clone()) as Box<dyn Any>)
+        }))
+    }
+
+    fn dimensions(&self, platform: &dyn MiddlewarePlatform) -> Option<Dimensions> {
+        match self {
+            // Layout sizes ignore transforms, so rotated arrows keep their size
+            ArrowElement::Node(element) => {
+                element().and_then(|element| platform.measure_dimensions(&*element))
+            }
+            ArrowElement::Dimensions(dimensions) => Some(*dimensions),
+        }
+    }
+}
+
+/// Arrow coordinates computed by [`arrow`], relative to the floating element
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ArrowData {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    /// How far the arrow is from pointing at the reference center, non-zero when clamped
+    pub center_offset: f64,
+}
+
+/// Position an arrow element so it points at the center of the reference
+///
+/// `padding` keeps the arrow away from the floating element's edges, e.g. its rounded corners.
+/// Place it after [`shift`](crate::shift) so the arrow follows the shifted floating element.
+pub fn arrow(element: impl Into<ArrowElement>, padding: f64) -> Box<dyn Middleware> {
+    Box::new(Arrow {
+        element: element.into(),
+        padding,
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Arrow {
+    element: ArrowElement,
+    padding: f64,
+}
+
+impl Middleware for Arrow {
+    fn name(&self) -> &'static str {
+        "arrow"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let Some(arrow_dimensions) = self.element.dimensions(state.platform) else {
+            return MiddlewareReturn::default();
+        };
+
+        let reference = state.rects.reference;
+        let floating = state.rects.floating;
+        let axis = Axis::alignment(state.side);
+        let coord = match axis {
+            Axis::X => state.x,
+            Axis::Y => state.y,
+        };
+        let arrow_length = arrow_dimensions.length(axis);
+
+        let end_diff =
+            reference.length(axis) + reference.start(axis) - coord - floating.length(axis);
+        let start_diff = coord - reference.start(axis);
+        let center_to_reference = end_diff / 2.0 - start_diff / 2.0;
+
+        let client_size = floating.length(axis);
+
+        // Keep the padding small enough for the arrow to fit inside the floating element
+        let largest_possible_padding = client_size / 2.0 - arrow_length / 2.0 - 1.0;
+        let padding = self.padding.min(largest_possible_padding);
+
+        let min = padding;
+        let max = client_size - arrow_length - padding;
+        let center = client_size / 2.0 - arrow_length / 2.0 + center_to_reference;
+        let offset = center.min(max).max(min);
+
+        let (x, y) = match axis {
+            Axis::X => (Some(offset), None),
+            Axis::Y => (None, Some(offset)),
+        };
+
+        MiddlewareReturn {
+            data: MiddlewareData::default().with(ArrowData {
+                x,
+                y,
+                center_offset: center - offset,
+            }),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use super::alignment_sides;
+use crate::{
+    Align, DetectOverflowOptions, Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState,
+    Placement, PlacementOverflow, Reset, Side, compute_coords, detect_overflow,
+};
+
+const SIDES: [Side; 4] = [Side::Top, Side::Right, Side::Bottom, Side::Left];
+const ALIGNS: [Align; 3] = [Align::Start, Align::Center, Align::End];
+
+#[derive(Debug, Clone)]
+pub struct AutoPlacementOptions {
+    /// Also count overflow along the alignment axis when ranking aligned placements
+    pub cross_axis: bool,
+    /// Only consider placements with this alignment, every alignment when `None`
+    pub alignment: Option<Align>,
+    /// With an `alignment` set, fall back to the opposite alignment when it has more space
+    pub auto_alignment: bool,
+    /// Placements to choose from, every side and alignment when `None`
+    pub allowed_placements: Option<Vec<Placement>>,
+    /// Minimum distance to keep from the boundary edges
+    pub padding: f64,
+}
+
+impl Default for AutoPlacementOptions {
+    fn default() -> Self {
+        Self {
+            cross_axis: false,
+            alignment: None,
+            auto_alignment: true,
+            allowed_placements: None,
+            padding: 0.0,
+        }
+    }
+}
+
+/// Overflow measured by [`auto_placement`] for every candidate placement
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AutoPlacementData {
+    pub overflows: Vec<PlacementOverflow>,
+}
+
+/// Choose the placement with the most space, ignoring the side requested in the options
+pub fn auto_placement(options: AutoPlacementOptions) -> Box<dyn Middleware> {
+    Box::new(AutoPlacement { options })
+}
+
+#[derive(Debug, Clone)]
+struct AutoPlacement {
+    options: AutoPlacementOptions,
+}
+
+impl AutoPlacement {
+    /// Candidate placements, those with the preferred alignment first
+    fn placements(&self) -> Vec<Placement> {
+        let allowed = self.options.allowed_placements.clone().unwrap_or_else(|| {
+            SIDES
+                .iter()
+                .flat_map(|side| {
+                    ALIGNS.iter().map(|align| Placement {
+                        side: *side,
+                        align: *align,
+                    })
+                })
+                .collect()
+        });
+
+        let Some(alignment) = self.options.alignment else {
+            return allowed;
+        };
+
+        let preferred = allowed
+            .iter()
+            .filter(|placement| placement.align == alignment);
+        let fallback = allowed.iter().filter(|placement| {
+            self.options.auto_alignment
+                && placement.align != alignment
+                && placement.align == alignment.opposite()
+        });
+
+        preferred.chain(fallback).copied().collect()
+    }
+
+    fn overflows(&self, state: &MiddlewareState, placement: Placement) -> Vec<f64> {
+        let (x, y) = compute_coords(
+            &state.rects,
+            placement.side,
+            placement.align,
+            state.rtl,
+            state.side_offset,
+            state.align_offset,
+        );
+
+        let placed = MiddlewareState {
+            x,
+            y,
+            side: placement.side,
+            align: placement.align,
+            ..state.clone()
+        };
+        let overflow = detect_overflow(
+            &placed,
+            DetectOverflowOptions {
+                padding: self.options.padding,
+                ..Default::default()
+            },
+        );
+        let (start, end) = alignment_sides(placement, &state.rects, state.rtl);
+
+        vec![
+            overflow.get(placement.side),
+            overflow.get(start),
+            overflow.get(end),
+        ]
+    }
+}
+
+impl Middleware for AutoPlacement {
+    fn name(&self) -> &'static str {
+        "autoPlacement"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let overflows: Vec<PlacementOverflow> = self
+            .placements()
+            .into_iter()
+            .map(|placement| PlacementOverflow {
+                placement,
+                overflows: self.overflows(state, placement),
+            })
+            .collect();
+
+        // Rank by the space left on the placement's side, most space first
+        let mut ranked: Vec<(&PlacementOverflow, f64)> = overflows
+            .iter()
+            .map(|data| {
+                let main = data.overflows.first().copied().unwrap_or(0.0);
+                let score = if self.options.cross_axis && data.placement.align != Align::Center {
+                    main + data.overflows.get(1).copied().unwrap_or(0.0)
+                } else {
+                    main
+                };
+                (data, score)
+            })
+            .collect();
+        ranked.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        // Prefer placements that fit entirely, centered ones need to fit on both alignment sides
+        let fits = ranked.iter().find(|(data, _)| {
+            let checked = if data.placement.align == Align::Center {
+                3
+            } else {
+                2
+            };
+            data.overflows
+                .iter()
+                .take(checked)
+                .all(|overflow| *overflow <= 0.0)
+        });
+
+        let Some((best, _)) = fits.or(ranked.first()) else {
+            return MiddlewareReturn::default();
+        };
+        let placement = best.placement;
+
+        let current = Placement {
+            side: state.side,
+            align: state.align,
+        };
+        MiddlewareReturn {
+            data: MiddlewareData::default().with(AutoPlacementData { overflows }),
+            reset: (placement != current).then(|| Reset::to_placement(placement)),
+            ..Default::default()
+        }
+    }
+}
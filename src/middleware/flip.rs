@@ -0,0 +1,194 @@
+use super::alignment_sides;
+use crate::{
+    Align, DetectOverflowOptions, Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState,
+    Placement, Reset, detect_overflow,
+};
+
+/// What to do when every placement overflows the boundary
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FallbackStrategy {
+    /// Use the placement with the least overflow
+    #[default]
+    BestFit,
+    /// Go back to the placement requested in the options
+    InitialPlacement,
+}
+
+#[derive(Debug, Clone)]
+pub struct FlipOptions {
+    /// Check overflow on the side the floating element is placed on
+    pub main_axis: bool,
+    /// Check overflow along the alignment axis
+    pub cross_axis: bool,
+    /// Placements to try, in order, after the initial one overflows
+    ///
+    /// Defaults to the opposite side, plus the opposite alignments when `flip_alignment` is set.
+    pub fallback_placements: Option<Vec<Placement>>,
+    pub fallback_strategy: FallbackStrategy,
+    /// Also try the opposite alignment when falling back
+    pub flip_alignment: bool,
+    /// Minimum distance to keep from the boundary edges
+    pub padding: f64,
+}
+
+impl Default for FlipOptions {
+    fn default() -> Self {
+        Self {
+            main_axis: true,
+            cross_axis: true,
+            fallback_placements: None,
+            fallback_strategy: FallbackStrategy::default(),
+            flip_alignment: true,
+            padding: 0.0,
+        }
+    }
+}
+
+/// Overflow measured for a placement that was tried
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacementOverflow {
+    pub placement: Placement,
+    /// Main axis overflow first (when checked), followed by both alignment sides
+    pub overflows: Vec<f64>,
+}
+
+/// Data stored by [`flip`] while it tries fallback placements
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FlipData {
+    /// Index of the placement currently being tried
+    pub index: usize,
+    pub overflows: Vec<PlacementOverflow>,
+}
+
+/// Flip the floating element to a fallback placement when it overflows its boundary
+pub fn flip(options: FlipOptions) -> Box<dyn Middleware> {
+    Box::new(Flip { options })
+}
+
+#[derive(Debug, Clone)]
+struct Flip {
+    options: FlipOptions,
+}
+
+impl Middleware for Flip {
+    fn name(&self) -> &'static str {
+        "flip"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let initial = Placement {
+            side: state.initial_side,
+            align: state.initial_align,
+        };
+        let current = Placement {
+            side: state.side,
+            align: state.align,
+        };
+
+        let fallback_placements = match &self.options.fallback_placements {
+            Some(placements) => placements.clone(),
+            None if initial.align == Align::Center || !self.options.flip_alignment => {
+                vec![initial.opposite()]
+            }
+            None => expanded_placements(initial),
+        };
+        let placements: Vec<Placement> = std::iter::once(initial)
+            .chain(fallback_placements)
+            .collect();
+
+        let overflow = detect_overflow(
+            state,
+            DetectOverflowOptions {
+                padding: self.options.padding,
+                ..Default::default()
+            },
+        );
+
+        let mut overflows = Vec::new();
+        if self.options.main_axis {
+            overflows.push(overflow.get(state.side));
+        }
+        if self.options.cross_axis {
+            let (start, end) = alignment_sides(current, &state.rects, state.rtl);
+            overflows.push(overflow.get(start));
+            overflows.push(overflow.get(end));
+        }
+
+        if overflows.iter().all(|overflow| *overflow <= 0.0) {
+            return MiddlewareReturn::default();
+        }
+
+        let previous = state.middleware_data.get::<FlipData>();
+        let mut overflows_data = previous
+            .map(|data| data.overflows.clone())
+            .unwrap_or_default();
+        overflows_data.push(PlacementOverflow {
+            placement: current,
+            overflows,
+        });
+
+        let next_index = previous.map_or(0, |data| data.index) + 1;
+        if let Some(next) = placements.get(next_index) {
+            return MiddlewareReturn {
+                data: MiddlewareData::default().with(FlipData {
+                    index: next_index,
+                    overflows: overflows_data,
+                }),
+                reset: Some(Reset::to_placement(*next)),
+                ..Default::default()
+            };
+        }
+
+        // Every placement overflows: prefer one that fits on the main axis, then fall back
+        let fits_main_axis = overflows_data
+            .iter()
+            .filter(|data| data.overflows.first().is_some_and(|main| *main <= 0.0))
+            .min_by(|a, b| cross_axis_overflow(a).total_cmp(&cross_axis_overflow(b)))
+            .map(|data| data.placement);
+
+        let placement = fits_main_axis.unwrap_or_else(|| match self.options.fallback_strategy {
+            FallbackStrategy::BestFit => overflows_data
+                .iter()
+                .min_by(|a, b| total_overflow(a).total_cmp(&total_overflow(b)))
+                .map_or(initial, |data| data.placement),
+            FallbackStrategy::InitialPlacement => initial,
+        });
+
+        if placement != current {
+            return MiddlewareReturn {
+                reset: Some(Reset::to_placement(placement)),
+                ..Default::default()
+            };
+        }
+
+        MiddlewareReturn::default()
+    }
+}
+
+fn cross_axis_overflow(data: &PlacementOverflow) -> f64 {
+    data.overflows.get(1).copied().unwrap_or(0.0)
+}
+
+fn total_overflow(data: &PlacementOverflow) -> f64 {
+    data.overflows
+        .iter()
+        .filter(|overflow| **overflow > 0.0)
+        .sum()
+}
+
+/// Opposite alignment, opposite side, then both flipped
+fn expanded_placements(placement: Placement) -> Vec<Placement> {
+    let opposite = placement.opposite();
+
+    vec![
+        Placement {
+            align: placement.align.opposite(),
+            ..placement
+        },
+        opposite,
+        Placement {
+            align: opposite.align.opposite(),
+            ..opposite
+        },
+    ]
+}
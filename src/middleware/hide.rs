@@ -0,0 +1,111 @@
+use crate::{
+    DetectOverflowOptions, ElementContext, Middleware, MiddlewareData, MiddlewareReturn,
+    MiddlewareState, Rect, SideObject, detect_overflow,
+};
+
+/// What [`hide`] checks for
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HideStrategy {
+    /// The reference element is fully clipped by the boundary
+    #[default]
+    ReferenceHidden,
+    /// The floating element has fully escaped the boundary
+    Escaped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HideOptions {
+    pub strategy: HideStrategy,
+    /// Distance from the boundary edges at which an element counts as clipped
+    pub padding: f64,
+}
+
+/// Visibility state computed by [`hide`]
+///
+/// Each strategy only fills in its own fields, so running `hide` once per strategy reports both.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HideData {
+    pub reference_hidden: Option<bool>,
+    /// Distance the reference has to move on each side to become visible again
+    pub reference_hidden_offsets: Option<SideObject>,
+    pub escaped: Option<bool>,
+    /// Distance the floating element has to move on each side to be back inside the boundary
+    pub escaped_offsets: Option<SideObject>,
+}
+
+/// Report when the reference is hidden or the floating element escaped the boundary
+pub fn hide(options: HideOptions) -> Box<dyn Middleware> {
+    Box::new(Hide { options })
+}
+
+#[derive(Debug, Clone)]
+struct Hide {
+    options: HideOptions,
+}
+
+impl Middleware for Hide {
+    fn name(&self) -> &'static str {
+        "hide"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let mut data = state
+            .middleware_data
+            .get::<HideData>()
+            .copied()
+            .unwrap_or_default();
+
+        match self.options.strategy {
+            HideStrategy::ReferenceHidden => {
+                let overflow = detect_overflow(
+                    state,
+                    DetectOverflowOptions {
+                        element_context: ElementContext::Reference,
+                        padding: self.options.padding,
+                        ..Default::default()
+                    },
+                );
+                let offsets = side_offsets(overflow, state.rects.reference);
+
+                data.reference_hidden = Some(is_any_side_fully_clipped(offsets));
+                data.reference_hidden_offsets = Some(offsets);
+            }
+            HideStrategy::Escaped => {
+                let overflow = detect_overflow(
+                    state,
+                    DetectOverflowOptions {
+                        element_context: ElementContext::Floating,
+                        padding: self.options.padding,
+                        alt_boundary: true,
+                        ..Default::default()
+                    },
+                );
+                let offsets = side_offsets(overflow, state.rects.floating);
+
+                data.escaped = Some(is_any_side_fully_clipped(offsets));
+                data.escaped_offsets = Some(offsets);
+            }
+        }
+
+        MiddlewareReturn {
+            data: MiddlewareData::default().with(data),
+            ..Default::default()
+        }
+    }
+}
+
+/// Overflow relative to the far edge of the element, non-negative once it is fully clipped
+fn side_offsets(overflow: SideObject, rect: Rect) -> SideObject {
+    SideObject {
+        top: overflow.top - rect.height,
+        right: overflow.right - rect.width,
+        bottom: overflow.bottom - rect.height,
+        left: overflow.left - rect.width,
+    }
+}
+
+fn is_any_side_fully_clipped(offsets: SideObject) -> bool {
+    [offsets.top, offsets.right, offsets.bottom, offsets.left]
+        .iter()
+        .any(|offset| *offset >= 0.0)
+}
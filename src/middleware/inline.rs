@@ -0,0 +1,146 @@
+use crate::{ElementRects, Middleware, MiddlewareReturn, MiddlewareState, Rect, Reset, Side};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct InlineOptions {
+    /// Pointer x-coordinate, used to pick the line the pointer is on
+    pub x: Option<f64>,
+    /// Pointer y-coordinate, used to pick the line the pointer is on
+    pub y: Option<f64>,
+    /// Extra distance around each line that still counts as hovering it
+    pub padding: f64,
+}
+
+/// Anchor to a single line of an inline reference that wraps across multiple lines
+///
+/// Uses [`MiddlewareState::reference_client_rects`] instead of the bounding box around all lines,
+/// so the floating element does not end up between them. Place it first in the middleware list.
+pub fn inline(options: InlineOptions) -> Box<dyn Middleware> {
+    Box::new(Inline { options })
+}
+
+#[derive(Debug, Clone)]
+struct Inline {
+    options: InlineOptions,
+}
+
+impl Middleware for Inline {
+    fn name(&self) -> &'static str {
+        "inline"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let lines = rects_by_line(&state.reference_client_rects);
+        let Some(reference) = self.reference_rect(&lines, state.side) else {
+            return MiddlewareReturn::default();
+        };
+
+        if reference == state.rects.reference {
+            return MiddlewareReturn::default();
+        }
+
+        MiddlewareReturn {
+            reset: Some(Reset {
+                rects: Some(ElementRects {
+                    reference,
+                    ..state.rects
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl Inline {
+    /// The part of the reference to anchor to, `None` when it fits on a single line
+    fn reference_rect(&self, lines: &[Rect], side: Side) -> Option<Rect> {
+        let (first, last) = match lines {
+            [] | [_] => return None,
+            [first, .., last] => (*first, *last),
+        };
+
+        // Two disjoint lines: anchor to the one under the pointer
+        if let ([first_line, second_line], Some(x), Some(y)) =
+            (lines, self.options.x, self.options.y)
+            && first_line.x > second_line.right()
+        {
+            let padding = self.options.padding;
+            let hovered = lines.iter().find(|line| {
+                x > line.x - padding
+                    && x < line.right() + padding
+                    && y > line.y - padding
+                    && y < line.bottom() + padding
+            });
+
+            return hovered.copied().or_else(|| Rect::bounding(lines));
+        }
+
+        match side {
+            // Span all lines vertically, horizontally match the line closest to the floating element
+            Side::Top | Side::Bottom => {
+                let line = if side == Side::Top { first } else { last };
+                Some(Rect {
+                    x: line.x,
+                    y: first.y,
+                    width: line.width,
+                    height: last.bottom() - first.y,
+                })
+            }
+            // Span the lines that reach furthest towards the floating element
+            Side::Left | Side::Right => {
+                let min_left = lines
+                    .iter()
+                    .map(|line| line.x)
+                    .fold(f64::INFINITY, f64::min);
+                let max_right = lines
+                    .iter()
+                    .map(|line| line.right())
+                    .fold(f64::NEG_INFINITY, f64::max);
+
+                let measured: Vec<&Rect> = lines
+                    .iter()
+                    .filter(|line| {
+                        if side == Side::Left {
+                            line.x == min_left
+                        } else {
+                            line.right() == max_right
+                        }
+                    })
+                    .collect();
+                let top = measured.first()?.y;
+                let bottom = measured.last()?.bottom();
+
+                Some(Rect {
+                    x: min_left,
+                    y: top,
+                    width: max_right - min_left,
+                    height: bottom - top,
+                })
+            }
+        }
+    }
+}
+
+/// Merge client rects into one rect per line of text
+fn rects_by_line(rects: &[Rect]) -> Vec<Rect> {
+    let mut sorted = rects.to_vec();
+    sorted.sort_by(|a, b| a.y.total_cmp(&b.y));
+
+    let mut groups: Vec<Vec<Rect>> = Vec::new();
+    let mut previous: Option<Rect> = None;
+
+    for rect in sorted {
+        match (previous, groups.last_mut()) {
+            (Some(previous), Some(group)) if rect.y - previous.y <= previous.height / 2.0 => {
+                group.push(rect);
+            }
+            _ => groups.push(vec![rect]),
+        }
+        previous = Some(rect);
+    }
+
+    groups
+        .iter()
+        .filter_map(|group| Rect::bounding(group))
+        .collect()
+}
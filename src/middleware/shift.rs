@@ -0,0 +1,183 @@
+use crate::geometry::Axis;
+use crate::{
+    DetectOverflowOptions, Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState, Side,
+    detect_overflow,
+};
+
+#[derive(Debug, Clone)]
+pub struct ShiftOptions {
+    /// Shift along the alignment axis, e.g. horizontally for `Side::Bottom`
+    pub main_axis: bool,
+    /// Shift along the side axis, which lets the floating element overlap the reference
+    pub cross_axis: bool,
+    /// Limit the shift so the floating element stays attached to the reference
+    pub limiter: Option<LimitShift>,
+    /// Minimum distance to keep from the boundary edges
+    pub padding: f64,
+}
+
+impl Default for ShiftOptions {
+    fn default() -> Self {
+        Self {
+            main_axis: true,
+            cross_axis: false,
+            limiter: None,
+            padding: 0.0,
+        }
+    }
+}
+
+/// Stop shifting once the floating element would detach from the reference
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LimitShift {
+    /// Distance before the reference edge at which shifting stops on the main axis
+    pub offset: f64,
+    pub main_axis: bool,
+    pub cross_axis: bool,
+}
+
+impl Default for LimitShift {
+    fn default() -> Self {
+        Self {
+            offset: 0.0,
+            main_axis: true,
+            cross_axis: true,
+        }
+    }
+}
+
+/// Distance [`shift`] moved the floating element by
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ShiftData {
+    pub x: f64,
+    pub y: f64,
+    /// Whether shifting was enabled along the x-axis
+    pub enabled_x: bool,
+    /// Whether shifting was enabled along the y-axis
+    pub enabled_y: bool,
+}
+
+/// Shift the floating element to keep it inside its boundary
+pub fn shift(options: ShiftOptions) -> Box<dyn Middleware> {
+    Box::new(Shift { options })
+}
+
+#[derive(Debug, Clone)]
+struct Shift {
+    options: ShiftOptions,
+}
+
+impl Middleware for Shift {
+    fn name(&self) -> &'static str {
+        "shift"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let overflow = detect_overflow(
+            state,
+            DetectOverflowOptions {
+                padding: self.options.padding,
+                ..Default::default()
+            },
+        );
+
+        let main_axis = Axis::alignment(state.side);
+        let cross_axis = main_axis.opposite();
+
+        let mut main_axis_coord = coord(state, main_axis);
+        let mut cross_axis_coord = coord(state, cross_axis);
+
+        if self.options.main_axis {
+            let (min_side, max_side) = main_axis.sides();
+            main_axis_coord = clamp(
+                main_axis_coord + overflow.get(min_side),
+                main_axis_coord,
+                main_axis_coord - overflow.get(max_side),
+            );
+        }
+
+        if self.options.cross_axis {
+            let (min_side, max_side) = cross_axis.sides();
+            cross_axis_coord = clamp(
+                cross_axis_coord + overflow.get(min_side),
+                cross_axis_coord,
+                cross_axis_coord - overflow.get(max_side),
+            );
+        }
+
+        if let Some(limiter) = self.options.limiter {
+            (main_axis_coord, cross_axis_coord) =
+                limiter.limit(state, main_axis, main_axis_coord, cross_axis_coord);
+        }
+
+        let (x, y) = match main_axis {
+            Axis::X => (main_axis_coord, cross_axis_coord),
+            Axis::Y => (cross_axis_coord, main_axis_coord),
+        };
+        let (enabled_x, enabled_y) = match main_axis {
+            Axis::X => (self.options.main_axis, self.options.cross_axis),
+            Axis::Y => (self.options.cross_axis, self.options.main_axis),
+        };
+
+        MiddlewareReturn {
+            x: Some(x),
+            y: Some(y),
+            data: MiddlewareData::default().with(ShiftData {
+                x: x - state.x,
+                y: y - state.y,
+                enabled_x,
+                enabled_y,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl LimitShift {
+    fn limit(
+        &self,
+        state: &MiddlewareState,
+        main_axis: Axis,
+        mut main_axis_coord: f64,
+        mut cross_axis_coord: f64,
+    ) -> (f64, f64) {
+        let reference = state.rects.reference;
+        let floating = state.rects.floating;
+        let cross_axis = main_axis.opposite();
+
+        if self.main_axis {
+            let limit_min = reference.start(main_axis) - floating.length(main_axis) + self.offset;
+            let limit_max = reference.start(main_axis) + reference.length(main_axis) - self.offset;
+            main_axis_coord = clamp(limit_min, main_axis_coord, limit_max);
+        }
+
+        if self.cross_axis {
+            // The side offset pushes the floating element away from the reference
+            let is_origin_side = matches!(state.side, Side::Top | Side::Left);
+            let (before, after) = if is_origin_side {
+                (state.side_offset, 0.0)
+            } else {
+                (0.0, state.side_offset)
+            };
+
+            let limit_min = reference.start(cross_axis) - floating.length(cross_axis) - before;
+            let limit_max = reference.start(cross_axis) + reference.length(cross_axis) + after;
+            cross_axis_coord = clamp(limit_min, cross_axis_coord, limit_max);
+        }
+
+        (main_axis_coord, cross_axis_coord)
+    }
+}
+
+fn coord(state: &MiddlewareState, axis: Axis) -> f64 {
+    match axis {
+        Axis::X => state.x,
+        Axis::Y => state.y,
+    }
+}
+
+/// Clamp that favours `min` when the range is empty, as happens when the element is larger than
+/// its boundary
+fn clamp(min: f64, value: f64, max: f64) -> f64 {
+    value.min(max).max(min)
+}
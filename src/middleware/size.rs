@@ -0,0 +1,130 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::{
+    Align, DetectOverflowOptions, Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState,
+    ShiftData, Side, detect_overflow,
+};
+
+/// Callback invoked by [`size`] with the space available to the floating element
+pub type SizeApply = Arc<dyn Fn(&MiddlewareState, &SizeData) + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub struct SizeOptions {
+    /// Called after the available size is computed, e.g. to set `max-height` on the element
+    pub apply: Option<SizeApply>,
+    /// Minimum distance to keep from the boundary edges
+    pub padding: f64,
+}
+
+impl fmt::Debug for SizeOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SizeOptions")
+            .field("apply", &self.apply.is_some())
+            .field("padding", &self.padding)
+            .finish()
+    }
+}
+
+/// Space available to the floating element before it overflows its boundary
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SizeData {
+    pub available_width: f64,
+    pub available_height: f64,
+}
+
+/// Compute the width and height the floating element can grow to without overflowing
+pub fn size(options: SizeOptions) -> Box<dyn Middleware> {
+    Box::new(Size { options })
+}
+
+#[derive(Debug, Clone)]
+struct Size {
+    options: SizeOptions,
+}
+
+impl Middleware for Size {
+    fn name(&self) -> &'static str {
+        "size"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        let overflow = detect_overflow(
+            state,
+            DetectOverflowOptions {
+                padding: self.options.padding,
+                ..Default::default()
+            },
+        );
+        let width = state.rects.floating.width;
+        let height = state.rects.floating.height;
+        let is_horizontal_side = matches!(state.side, Side::Top | Side::Bottom);
+
+        // Sides the floating element grows towards from its current position
+        let (width_side, height_side) = if is_horizontal_side {
+            let end = if state.rtl { Align::Start } else { Align::End };
+            let width_side = if state.align == end {
+                Side::Left
+            } else {
+                Side::Right
+            };
+            (width_side, state.side)
+        } else {
+            let height_side = match state.align {
+                Align::End => Side::Top,
+                _ => Side::Bottom,
+            };
+            (state.side, height_side)
+        };
+
+        let maximum_clipping_width = width - overflow.left - overflow.right;
+        let maximum_clipping_height = height - overflow.top - overflow.bottom;
+
+        let mut available_width = (width - overflow.get(width_side)).min(maximum_clipping_width);
+        let mut available_height =
+            (height - overflow.get(height_side)).min(maximum_clipping_height);
+
+        let shift_data = state.middleware_data.get::<ShiftData>();
+        if let Some(shift_data) = shift_data {
+            // Shifting moves the element within the whole boundary, so all of it is available
+            if shift_data.enabled_x {
+                available_width = maximum_clipping_width;
+            }
+            if shift_data.enabled_y {
+                available_height = maximum_clipping_height;
+            }
+        } else if state.align == Align::Center {
+            // A centered element grows in both directions, so the tighter side limits it
+            if is_horizontal_side {
+                available_width = width - 2.0 * symmetric_overflow(overflow.left, overflow.right);
+            } else {
+                available_height = height - 2.0 * symmetric_overflow(overflow.top, overflow.bottom);
+            }
+        }
+
+        let data = SizeData {
+            available_width,
+            available_height,
+        };
+
+        if let Some(apply) = &self.options.apply {
+            apply(state, &data);
+        }
+
+        MiddlewareReturn {
+            data: MiddlewareData::default().with(data),
+            ..Default::default()
+        }
+    }
+}
+
+fn symmetric_overflow(start: f64, end: f64) -> f64 {
+    let start_overflow = start.max(0.0);
+    let end_overflow = end.max(0.0);
+
+    if start_overflow != 0.0 || end_overflow != 0.0 {
+        start_overflow + end_overflow
+    } else {
+        start.max(end)
+    }
+}
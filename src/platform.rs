@@ -0,0 +1,323 @@
+use std::any::Any;
+use std::fmt;
+use std::rc::Rc;
+
+use leptos::prelude::{document, request_animation_frame, window};
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::{Element, HtmlElement};
+
+use crate::clipping::get_clipping_rect;
+use crate::dom::{
+    bounding_client_rect, computed_style, is_containing_block, is_overflow_element, parent_element,
+};
+use crate::{
+    AutoUpdateCleanup, AutoUpdateOptions, Boundary, Coords, Dimensions, ElementRects, Rect,
+    Reference, RootBoundary, Strategy,
+};
+
+/// Environment the elements live in, queried for the measurements the position is computed from
+///
+/// [`DomPlatform`] measures real DOM elements. Implement this trait to position elements of a
+/// custom renderer, or to run the middleware pipeline against fixed layouts in native tests.
+pub trait Platform {
+    /// Handle to an element on this platform
+    type Element: 'static;
+
+    /// Rects of both elements, the reference relative to the viewport and the floating element
+    /// at the origin
+    fn get_element_rects(
+        &self,
+        reference: &Self::Element,
+        floating: &Self::Element,
+    ) -> ElementRects;
+
+    /// Rect the element is clipped by, the intersection of `boundary` and `root_boundary`
+    fn get_clipping_rect(
+        &self,
+        element: &Self::Element,
+        boundary: &Boundary,
+        root_boundary: &RootBoundary,
+    ) -> Rect;
+
+    /// Layout size of the element, ignoring transforms
+    fn get_dimensions(&self, element: &Self::Element) -> Dimensions;
+
+    /// Ancestor the element's coordinates are relative to with `strategy`, `None` for the
+    /// document or viewport
+    ///
+    /// This is the closest positioned ancestor for `Strategy::Absolute`, unless a containing block
+    /// such as a transformed ancestor is closer. For `Strategy::Fixed` it is the closest containing
+    /// block.
+    fn get_offset_parent(
+        &self,
+        element: &Self::Element,
+        strategy: Strategy,
+    ) -> Option<Self::Element>;
+
+    /// Whether the element's text direction is right-to-left
+    fn is_rtl(&self, element: &Self::Element) -> bool;
+
+    /// Convert a rect relative to `offset_parent` to one relative to the viewport
+    ///
+    /// Without an offset parent, `Strategy::Absolute` coordinates are relative to the document
+    /// and `Strategy::Fixed` coordinates are relative to the viewport.
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        rect: Rect,
+        offset_parent: Option<&Self::Element>,
+        strategy: Strategy,
+    ) -> Rect;
+
+    /// Ratio between the element's rendered and layout size, e.g. from a CSS `scale` transform
+    fn get_scale(&self, element: &Self::Element) -> Coords;
+
+    /// Number of device pixels per CSS pixel, used to round the coordinates
+    fn get_device_pixel_ratio(&self) -> f64 {
+        1.0
+    }
+
+    /// One rect per line box of an inline element, used by [`inline`](crate::inline)
+    fn get_client_rects(&self, element: &Self::Element) -> Vec<Rect> {
+        let _ = element;
+        Vec::new()
+    }
+
+    /// Run `update` once the elements have been laid out, immediately by default
+    fn schedule_update(&self, update: Box<dyn FnOnce()>) {
+        update();
+    }
+
+    /// Call `update` whenever the position may have changed, until the returned cleanup is dropped
+    ///
+    /// Used by [`use_floating`](crate::use_floating) when
+    /// [`FloatingOptions::auto_update`](crate::FloatingOptions::auto_update) is set. Platforms
+    /// that cannot observe changes return `None`.
+    fn auto_update(
+        &self,
+        reference: &Reference<Self::Element>,
+        floating: &Self::Element,
+        update: Rc<dyn Fn()>,
+        options: AutoUpdateOptions,
+    ) -> Option<AutoUpdateCleanup> {
+        let _ = (reference, floating, update, options);
+        None
+    }
+}
+
+/// The [`Platform`] in use, as seen by middleware through [`MiddlewareState::platform`]
+///
+/// Middleware are not generic over the platform, so elements they measure, such as the
+/// [`arrow`](crate::arrow) element, are passed as `dyn Any` and measured when they are elements
+/// of this platform.
+///
+/// [`MiddlewareState::platform`]: crate::MiddlewareState::platform
+pub trait MiddlewarePlatform {
+    /// Layout size of the element, `None` when it is not an element of this platform
+    fn measure_dimensions(&self, element: &dyn Any) -> Option<Dimensions>;
+}
+
+impl<P: Platform> MiddlewarePlatform for P {
+    fn measure_dimensions(&self, element: &dyn Any) -> Option<Dimensions> {
+        element
+            .downcast_ref::<P::Element>()
+            .map(|element| self.get_dimensions(element))
+    }
+}
+
+impl fmt::Debug for dyn MiddlewarePlatform + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MiddlewarePlatform")
+    }
+}
+
+/// Platform measuring DOM elements through `web_sys`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DomPlatform;
+
+impl Platform for DomPlatform {
+    type Element = Element;
+
+    fn get_element_rects(&self, reference: &Element, floating: &Element) -> ElementRects {
+        let floating = bounding_client_rect(floating);
+
+        ElementRects {
+            reference: bounding_client_rect(reference),
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                ..floating
+            },
+        }
+    }
+
+    fn get_clipping_rect(
+        &self,
+        element: &Element,
+        boundary: &Boundary,
+        root_boundary: &RootBoundary,
+    ) -> Rect {
+        get_clipping_rect(element, boundary, root_boundary)
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        match element.dyn_ref::<HtmlElement>() {
+            Some(element) => Dimensions {
+                width: element.offset_width().into(),
+                height: element.offset_height().into(),
+            },
+            // SVG elements have no layout size, use their rendered size instead
+            None => {
+                let rect = bounding_client_rect(element);
+                Dimensions {
+                    width: rect.width,
+                    height: rect.height,
+                }
+            }
+        }
+    }
+
+    fn get_offset_parent(&self, element: &Element, strategy: Strategy) -> Option<Element> {
+        let offset_parent = match strategy {
+            Strategy::Absolute => element
+                .dyn_ref::<HtmlElement>()
+                .and_then(HtmlElement::offset_parent)
+                .filter(|offset_parent| {
+                    // A static root element means the coordinates are relative to the document itself
+                    let is_root = ["html", "body"]
+                        .iter()
+                        .any(|name| offset_parent.node_name().eq_ignore_ascii_case(name));
+                    let is_static = computed_style(offset_parent)
+                        .and_then(|style| style.get_property_value("position").ok())
+                        .is_none_or(|position| position == "static");
+                    !(is_root && is_static)
+                }),
+            Strategy::Fixed => None,
+        };
+
+        // `offsetParent` only reports positioned ancestors, a closer containing block wins
+        let mut current = parent_element(element);
+        while let Some(ancestor) = current {
+            if offset_parent.as_ref() == Some(&ancestor) {
+                break;
+            }
+            if is_containing_block(&ancestor) {
+                return Some(ancestor);
+            }
+            if ancestor.node_name().eq_ignore_ascii_case("html") {
+                break;
+            }
+            current = parent_element(&ancestor);
+        }
+
+        offset_parent
+    }
+
+    fn is_rtl(&self, element: &Element) -> bool {
+        computed_style(element)
+            .and_then(|style| style.get_property_value("direction").ok())
+            .is_some_and(|direction| direction == "rtl")
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        rect: Rect,
+        offset_parent: Option<&Element>,
+        strategy: Strategy,
+    ) -> Rect {
+        let Some(offset_parent) = offset_parent else {
+            return match strategy {
+                // Document coordinates move up and left as the page scrolls
+                Strategy::Absolute => Rect {
+                    x: rect.x - window().scroll_x().unwrap_or_default(),
+                    y: rect.y - window().scroll_y().unwrap_or_default(),
+                    ..rect
+                },
+                Strategy::Fixed => rect,
+            };
+        };
+        let document_element = document().document_element();
+        if document_element.as_ref() == Some(offset_parent) {
+            return rect;
+        }
+
+        // The body only scrolls its content when the document element clips it
+        let scrolls = !offset_parent.node_name().eq_ignore_ascii_case("body")
+            || document_element.as_ref().is_some_and(is_overflow_element);
+        let scroll = if scrolls {
+            Coords {
+                x: offset_parent.scroll_left().into(),
+                y: offset_parent.scroll_top().into(),
+            }
+        } else {
+            Coords::default()
+        };
+
+        let offset_rect = bounding_client_rect(offset_parent);
+        let scale = self.get_scale(offset_parent);
+        let offsets = Coords {
+            x: offset_rect.x + f64::from(offset_parent.client_left()),
+            y: offset_rect.y + f64::from(offset_parent.client_top()),
+        };
+
+        Rect {
+            x: rect.x * scale.x - scroll.x * scale.x + offsets.x,
+            y: rect.y * scale.y - scroll.y * scale.y + offsets.y,
+            width: rect.width * scale.x,
+            height: rect.height * scale.y,
+        }
+    }
+
+    fn get_scale(&self, element: &Element) -> Coords {
+        let rect = bounding_client_rect(element);
+        let dimensions = self.get_dimensions(element);
+        let ratio = |rendered: f64, layout: f64| {
+            let ratio = rendered / layout;
+            if ratio.is_finite() && ratio != 0.0 {
+                ratio
+            } else {
+                1.0
+            }
+        };
+
+        Coords {
+            x: ratio(rect.width, dimensions.width),
+            y: ratio(rect.height, dimensions.height),
+        }
+    }
+
+    fn get_device_pixel_ratio(&self) -> f64 {
+        window().device_pixel_ratio()
+    }
+
+    fn get_client_rects(&self, element: &Element) -> Vec<Rect> {
+        let client_rects = element.get_client_rects();
+        (0..client_rects.length())
+            .filter_map(|index| client_rects.item(index))
+            .map(|rect| Rect {
+                x: rect.left(),
+                y: rect.top(),
+                width: rect.width(),
+                height: rect.height(),
+            })
+            .collect()
+    }
+
+    fn schedule_update(&self, update: Box<dyn FnOnce()>) {
+        request_animation_frame(update);
+    }
+
+    fn auto_update(
+        &self,
+        reference: &Reference<Element>,
+        floating: &Element,
+        update: Rc<dyn Fn()>,
+        options: AutoUpdateOptions,
+    ) -> Option<AutoUpdateCleanup> {
+        Some(crate::auto_update(
+            reference.clone(),
+            floating,
+            move || update(),
+            options,
+        ))
+    }
+}
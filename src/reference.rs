@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use leptos::prelude::*;
+use leptos::tachys::html::element::ElementType;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::Element;
+use send_wrapper::SendWrapper;
+
+use crate::Rect;
+
+/// Element a floating element is anchored to, either a real element or a [`VirtualElement`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reference<E> {
+    Element(E),
+    Virtual(VirtualElement),
+}
+
+impl<E> From<E> for Reference<E> {
+    fn from(element: E) -> Self {
+        Reference::Element(element)
+    }
+}
+
+impl<T: AsRef<Element>> From<&T> for Reference<Element> {
+    fn from(element: &T) -> Self {
+        Reference::Element(element.as_ref().clone())
+    }
+}
+
+impl From<VirtualElement> for Reference<Element> {
+    fn from(virtual_element: VirtualElement) -> Self {
+        Reference::Virtual(virtual_element)
+    }
+}
+
+/// Measures the rect of a [`VirtualElement`]
+pub type BoundingClientRect = Arc<dyn Fn() -> Rect + Send + Sync>;
+
+/// Measures the line boxes of a [`VirtualElement`]
+pub type ClientRects = Arc<dyn Fn() -> Vec<Rect> + Send + Sync>;
+
+/// Reference that is not an element, such as the mouse position or a text selection range
+///
+/// The position is recalculated whenever a signal read by its closures changes.
+#[derive(Clone)]
+pub struct VirtualElement {
+    get_bounding_client_rect: BoundingClientRect,
+    get_client_rects: Option<ClientRects>,
+    context_element: Option<SendWrapper<Element>>,
+}
+
+impl VirtualElement {
+    /// A virtual element measured by `get_bounding_client_rect`, relative to the viewport
+    pub fn new(get_bounding_client_rect: impl Fn() -> Rect + Send + Sync + 'static) -> Self {
+        Self {
+            get_bounding_client_rect: Arc::new(get_bounding_client_rect),
+            get_client_rects: None,
+            context_element: None,
+        }
+    }
+
+    /// Measure the line boxes of a multi-line reference, used by [`inline`](crate::inline)
+    pub fn with_client_rects(
+        mut self,
+        get_client_rects: impl Fn() -> Vec<Rect> + Send + Sync + 'static,
+    ) -> Self {
+        self.get_client_rects = Some(Arc::new(get_client_rects));
+        self
+    }
+
+    /// Element the virtual reference lives in, whose scroll containers are watched by
+    /// [`auto_update`](crate::auto_update)
+    pub fn with_context_element(mut self, context_element: Element) -> Self {
+        self.context_element = Some(SendWrapper::new(context_element));
+        self
+    }
+
+    /// The rect of the virtual element, relative to the viewport
+    pub fn bounding_client_rect(&self) -> Rect {
+        (self.get_bounding_client_rect)()
+    }
+
+    /// The line boxes of the virtual element, empty unless set with
+    /// [`with_client_rects`](Self::with_client_rects)
+    pub fn client_rects(&self) -> Vec<Rect> {
+        self.get_client_rects
+            .as_ref()
+            .map_or_else(Vec::new, |get_client_rects| get_client_rects())
+    }
+
+    /// The element set with [`with_context_element`](Self::with_context_element)
+    pub fn context_element(&self) -> Option<&Element> {
+        self.context_element.as_deref()
+    }
+}
+
+impl std::fmt::Debug for VirtualElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualElement")
+            .field("bounding_client_rect", &self.bounding_client_rect())
+            .field("client_rects", &self.get_client_rects.is_some())
+            .field("context_element", &self.context_element.is_some())
+            .finish()
+    }
+}
+
+impl PartialEq for VirtualElement {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(
+            &self.get_bounding_client_rect,
+            &other.get_bounding_client_rect,
+        )
+    }
+}
+
+/// A reference [`use_floating`](crate::use_floating) can anchor to
+pub trait IntoReference {
+    /// Tracked getter for the current reference, `None` while it is not mounted
+    fn into_reference(self) -> impl Fn() -> Option<Reference<Element>> + 'static;
+}
+
+impl<E> IntoReference for NodeRef<E>
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + Into<Element> + 'static,
+{
+    fn into_reference(self) -> impl Fn() -> Option<Reference<Element>> + 'static {
+        move || self.get().map(|element| Reference::Element(element.into()))
+    }
+}
+
+impl IntoReference for VirtualElement {
+    fn into_reference(self) -> impl Fn() -> Option<Reference<Element>> + 'static {
+        move || Some(Reference::Virtual(self.clone()))
+    }
+}
+
+impl IntoReference for Signal<Option<VirtualElement>> {
+    fn into_reference(self) -> impl Fn() -> Option<Reference<Element>> + 'static {
+        move || self.get().map(Reference::Virtual)
+    }
+}
@@ -0,0 +1,451 @@
+//! In-memory platform for running
+//! [`use_floating_with_platform`](crate::use_floating_with_platform) in native tests
+//!
+//! ```
+//! use leptos::prelude::*;
+//! use leptos_floating::testing::{MockPlatform, flush_effects, init_executor};
+//! use leptos_floating::*;
+//!
+//! init_executor();
+//! let owner = Owner::new();
+//! owner.set();
+//!
+//! let platform = MockPlatform::default();
+//! let reference = platform.element(Rect {
+//!     x: 100.0,
+//!     y: 200.0,
+//!     width: 120.0,
+//!     height: 40.0,
+//! });
+//! let floating = platform.element(Rect {
+//!     x: 0.0,
+//!     y: 0.0,
+//!     width: 80.0,
+//!     height: 30.0,
+//! });
+//!
+//! let floating = use_floating_with_platform(
+//!     platform,
+//!     move || Some(reference),
+//!     move || Some(floating),
+//!     FloatingOptions::default(),
+//! );
+//! flush_effects();
+//!
+//! assert_eq!(floating.y.get_untracked(), 240.0);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use any_spawner::Executor;
+
+use crate::{
+    AutoUpdateCleanup, AutoUpdateOptions, Boundary, Coords, Dimensions, ElementRects, Platform,
+    Rect, Reference, RootBoundary, Strategy,
+};
+
+/// Set up an executor so effects run in native tests, safe to call from every test
+pub fn init_executor() {
+    let _ = Executor::init_futures_executor();
+}
+
+/// Run all pending effects on the current thread, e.g. after creating or changing elements
+pub fn flush_effects() {
+    Executor::poll_local();
+}
+
+/// Handle to an element declared on a [`MockPlatform`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MockElement(usize);
+
+/// Platform with declared element rects, scroll containers, clipping ancestors, offset parents and
+/// containing blocks
+///
+/// Element rects are declared relative to the viewport with every scroll container scrolled to
+/// the top. Clones share the same layout, so changes made in a test are seen by
+/// [`use_floating_with_platform`](crate::use_floating_with_platform), and trigger an update
+/// when [`FloatingOptions::auto_update`](crate::FloatingOptions::auto_update) is set.
+#[derive(Debug, Clone, Default)]
+pub struct MockPlatform(Rc<RefCell<MockLayout>>);
+
+#[derive(Debug)]
+struct MockLayout {
+    viewport: Rect,
+    device_pixel_ratio: f64,
+    elements: Vec<MockNode>,
+    listeners: HashMap<usize, MockListener>,
+    next_listener: usize,
+}
+
+impl Default for MockLayout {
+    fn default() -> Self {
+        Self {
+            viewport: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 1024.0,
+                height: 768.0,
+            },
+            device_pixel_ratio: 1.0,
+            elements: Vec::new(),
+            listeners: HashMap::new(),
+            next_listener: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MockNode {
+    rect: Rect,
+    client_rects: Vec<Rect>,
+    parent: Option<MockElement>,
+    offset_parent: Option<MockElement>,
+    containing_block: bool,
+    clips: bool,
+    scroll: Option<Coords>,
+    rtl: bool,
+    scale: Coords,
+}
+
+struct MockListener {
+    reference: Option<MockElement>,
+    floating: MockElement,
+    update: Rc<dyn Fn()>,
+    options: AutoUpdateOptions,
+}
+
+impl std::fmt::Debug for MockListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockListener")
+            .field("reference", &self.reference)
+            .field("floating", &self.floating)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Removes an auto update listener from the platform when dropped
+struct MockListenerGuard {
+    layout: Rc<RefCell<MockLayout>>,
+    id: usize,
+}
+
+impl Drop for MockListenerGuard {
+    fn drop(&mut self) {
+        if let Ok(mut layout) = self.layout.try_borrow_mut() {
+            layout.listeners.remove(&self.id);
+        }
+    }
+}
+
+impl MockPlatform {
+    /// Declare an element, with its rect relative to the viewport
+    pub fn element(&self, rect: Rect) -> MockElement {
+        let mut layout = self.0.borrow_mut();
+        layout.elements.push(MockNode {
+            rect,
+            client_rects: Vec::new(),
+            parent: None,
+            offset_parent: None,
+            containing_block: false,
+            clips: false,
+            scroll: None,
+            rtl: false,
+            scale: Coords { x: 1.0, y: 1.0 },
+        });
+        MockElement(layout.elements.len() - 1)
+    }
+
+    /// Resize the viewport
+    pub fn set_viewport(&self, viewport: Rect) {
+        self.0.borrow_mut().viewport = viewport;
+        self.notify(|listener| listener.options.ancestor_resize);
+    }
+
+    /// Set the number of device pixels per CSS pixel
+    pub fn set_device_pixel_ratio(&self, device_pixel_ratio: f64) {
+        self.0.borrow_mut().device_pixel_ratio = device_pixel_ratio;
+    }
+
+    /// Move or resize an element
+    pub fn set_rect(&self, element: MockElement, rect: Rect) {
+        let previous = std::mem::replace(&mut self.0.borrow_mut().node_mut(element).rect, rect);
+        let resized = previous.width != rect.width || previous.height != rect.height;
+        let moved = previous.x != rect.x || previous.y != rect.y;
+
+        self.notify(|listener| {
+            let is_reference = listener.reference == Some(element);
+            let observed = is_reference || listener.floating == element;
+            let tracks_moves = listener.options.layout_shift || listener.options.animation_frame;
+            (observed && resized && listener.options.element_resize)
+                || (is_reference && moved && tracks_moves)
+        });
+    }
+
+    /// Set the rects of an inline element's line boxes, used by [`inline`](crate::inline)
+    pub fn set_client_rects(&self, element: MockElement, client_rects: Vec<Rect>) {
+        self.0.borrow_mut().node_mut(element).client_rects = client_rects;
+    }
+
+    /// Place an element inside `parent`, whose clipping and scrolling then applies to it
+    pub fn set_parent(&self, element: MockElement, parent: MockElement) {
+        self.0.borrow_mut().node_mut(element).parent = Some(parent);
+    }
+
+    /// Make an element clip its descendants, like `overflow: hidden`
+    pub fn set_clips(&self, element: MockElement, clips: bool) {
+        self.0.borrow_mut().node_mut(element).clips = clips;
+    }
+
+    /// Make an element a scroll container, which clips its descendants and can be scrolled
+    pub fn set_scroll_container(&self, element: MockElement) {
+        let mut layout = self.0.borrow_mut();
+        let node = layout.node_mut(element);
+        node.clips = true;
+        node.scroll.get_or_insert_with(Coords::default);
+    }
+
+    /// Scroll a scroll container, moving its descendants by the opposite amount
+    pub fn scroll_to(&self, element: MockElement, scroll: Coords) {
+        self.0.borrow_mut().node_mut(element).scroll = Some(scroll);
+
+        let layout = Rc::clone(&self.0);
+        self.notify(|listener| {
+            let layout = layout.borrow();
+            listener.options.ancestor_scroll
+                && (listener
+                    .reference
+                    .is_some_and(|reference| layout.is_ancestor(element, reference))
+                    || layout.is_ancestor(element, listener.floating))
+        });
+    }
+
+    /// Set the positioned ancestor the element's coordinates are relative to
+    pub fn set_offset_parent(&self, element: MockElement, offset_parent: Option<MockElement>) {
+        self.0.borrow_mut().node_mut(element).offset_parent = offset_parent;
+    }
+
+    /// Make an element the containing block of its descendants, like a `transform` does
+    pub fn set_containing_block(&self, element: MockElement, containing_block: bool) {
+        self.0.borrow_mut().node_mut(element).containing_block = containing_block;
+    }
+
+    /// Set whether an element's text direction is right-to-left
+    pub fn set_rtl(&self, element: MockElement, rtl: bool) {
+        self.0.borrow_mut().node_mut(element).rtl = rtl;
+    }
+
+    /// Set the ratio between an element's rendered and layout size
+    pub fn set_scale(&self, element: MockElement, scale: Coords) {
+        self.0.borrow_mut().node_mut(element).scale = scale;
+    }
+
+    /// Call the updates of every auto update listener matching `filter`
+    fn notify(&self, filter: impl Fn(&MockListener) -> bool) {
+        // Collect first, updates measure elements through the layout
+        let updates: Vec<_> = self
+            .0
+            .borrow()
+            .listeners
+            .values()
+            .filter(|listener| filter(listener))
+            .map(|listener| Rc::clone(&listener.update))
+            .collect();
+
+        for update in updates {
+            update();
+        }
+    }
+}
+
+impl MockLayout {
+    fn node(&self, element: MockElement) -> &MockNode {
+        &self.elements[element.0]
+    }
+
+    fn node_mut(&mut self, element: MockElement) -> &mut MockNode {
+        &mut self.elements[element.0]
+    }
+
+    fn ancestors(&self, element: MockElement) -> impl Iterator<Item = MockElement> + '_ {
+        std::iter::successors(self.node(element).parent, |parent| {
+            self.node(*parent).parent
+        })
+    }
+
+    fn is_ancestor(&self, ancestor: MockElement, element: MockElement) -> bool {
+        self.ancestors(element).any(|parent| parent == ancestor)
+    }
+
+    /// Total scroll of the scroll containers around an element
+    fn scroll_offset(&self, element: MockElement) -> Coords {
+        self.ancestors(element)
+            .filter_map(|ancestor| self.node(ancestor).scroll)
+            .fold(Coords::default(), |total, scroll| Coords {
+                x: total.x + scroll.x,
+                y: total.y + scroll.y,
+            })
+    }
+
+    /// Rect relative to the viewport, after scrolling
+    fn rect(&self, element: MockElement) -> Rect {
+        let scroll = self.scroll_offset(element);
+        let rect = self.node(element).rect;
+
+        Rect {
+            x: rect.x - scroll.x,
+            y: rect.y - scroll.y,
+            ..rect
+        }
+    }
+}
+
+impl Platform for MockPlatform {
+    type Element = MockElement;
+
+    fn get_element_rects(&self, reference: &MockElement, floating: &MockElement) -> ElementRects {
+        let layout = self.0.borrow();
+
+        ElementRects {
+            reference: layout.rect(*reference),
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                ..layout.node(*floating).rect
+            },
+        }
+    }
+
+    fn get_clipping_rect(
+        &self,
+        element: &MockElement,
+        boundary: &Boundary,
+        root_boundary: &RootBoundary,
+    ) -> Rect {
+        let layout = self.0.borrow();
+
+        let boundary = match boundary {
+            Boundary::ClippingAncestors => layout
+                .ancestors(*element)
+                .filter(|ancestor| layout.node(*ancestor).clips)
+                .fold(Rect::UNBOUNDED, |clipping_rect, ancestor| {
+                    clipping_rect.intersection(&layout.rect(ancestor))
+                }),
+            Boundary::Rect(rect) => *rect,
+        };
+        let root_boundary = match root_boundary {
+            RootBoundary::Viewport => layout.viewport,
+            RootBoundary::Rect(rect) => *rect,
+        };
+
+        boundary.intersection(&root_boundary)
+    }
+
+    fn get_dimensions(&self, element: &MockElement) -> Dimensions {
+        let node = self.0.borrow().node(*element).clone();
+
+        Dimensions {
+            width: node.rect.width / node.scale.x,
+            height: node.rect.height / node.scale.y,
+        }
+    }
+
+    fn get_offset_parent(&self, element: &MockElement, strategy: Strategy) -> Option<MockElement> {
+        let layout = self.0.borrow();
+        let offset_parent = match strategy {
+            Strategy::Absolute => layout.node(*element).offset_parent,
+            Strategy::Fixed => None,
+        };
+
+        // A containing block closer than the offset parent takes precedence
+        layout
+            .ancestors(*element)
+            .take_while(|ancestor| Some(*ancestor) != offset_parent)
+            .find(|ancestor| layout.node(*ancestor).containing_block)
+            .or(offset_parent)
+    }
+
+    fn is_rtl(&self, element: &MockElement) -> bool {
+        self.0.borrow().node(*element).rtl
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        rect: Rect,
+        offset_parent: Option<&MockElement>,
+        _strategy: Strategy,
+    ) -> Rect {
+        // The mock document never scrolls, so it lines up with the viewport for both strategies
+        let Some(offset_parent) = offset_parent else {
+            return rect;
+        };
+        let layout = self.0.borrow();
+        let offset_rect = layout.rect(*offset_parent);
+        let node = layout.node(*offset_parent);
+        let scroll = node.scroll.unwrap_or_default();
+        let scale = node.scale;
+
+        Rect {
+            x: rect.x * scale.x - scroll.x * scale.x + offset_rect.x,
+            y: rect.y * scale.y - scroll.y * scale.y + offset_rect.y,
+            width: rect.width * scale.x,
+            height: rect.height * scale.y,
+        }
+    }
+
+    fn get_scale(&self, element: &MockElement) -> Coords {
+        self.0.borrow().node(*element).scale
+    }
+
+    fn get_device_pixel_ratio(&self) -> f64 {
+        self.0.borrow().device_pixel_ratio
+    }
+
+    fn get_client_rects(&self, element: &MockElement) -> Vec<Rect> {
+        let layout = self.0.borrow();
+        let scroll = layout.scroll_offset(*element);
+
+        layout
+            .node(*element)
+            .client_rects
+            .iter()
+            .map(|rect| Rect {
+                x: rect.x - scroll.x,
+                y: rect.y - scroll.y,
+                ..*rect
+            })
+            .collect()
+    }
+
+    fn auto_update(
+        &self,
+        reference: &Reference<MockElement>,
+        floating: &MockElement,
+        update: Rc<dyn Fn()>,
+        options: AutoUpdateOptions,
+    ) -> Option<AutoUpdateCleanup> {
+        let mut layout = self.0.borrow_mut();
+        let id = layout.next_listener;
+        layout.next_listener += 1;
+        layout.listeners.insert(
+            id,
+            MockListener {
+                // Virtual references are only watched through the floating element's ancestors
+                reference: match reference {
+                    Reference::Element(element) => Some(*element),
+                    Reference::Virtual(_) => None,
+                },
+                floating: *floating,
+                update,
+                options,
+            },
+        );
+
+        Some(AutoUpdateCleanup::new(MockListenerGuard {
+            layout: Rc::clone(&self.0),
+            id,
+        }))
+    }
+}
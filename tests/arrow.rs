@@ -0,0 +1,122 @@
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+const ARROW: Dimensions = Dimensions {
+    width: 10.0,
+    height: 10.0,
+};
+
+fn rects(reference_x: f64, reference_width: f64) -> ElementRects {
+    ElementRects {
+        reference: Rect {
+            x: reference_x,
+            y: 200.0,
+            width: reference_width,
+            height: 40.0,
+        },
+        floating: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 80.0,
+            height: 30.0,
+        },
+    }
+}
+
+fn arrow_data(position: &FloatingPosition) -> ArrowData {
+    *position.middleware_data.get::<ArrowData>().unwrap()
+}
+
+#[test]
+fn centers_arrow_under_reference() {
+    // Floating x=120, reference center at 160, arrow left at 160 - 120 - 5 = 35
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Center,
+        middleware: vec![arrow(ARROW, 0.0)],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 120.0), VIEWPORT, options).unwrap();
+
+    assert_eq!(
+        arrow_data(&pos),
+        ArrowData {
+            x: Some(35.0),
+            y: None,
+            center_offset: 0.0,
+        }
+    );
+}
+
+#[test]
+fn uses_y_axis_for_horizontal_sides() {
+    // Reference center at y=220, floating y=205, arrow top at 220 - 205 - 5 = 10
+    let options = FloatingOptions {
+        side: Side::Right,
+        align: Align::Center,
+        middleware: vec![arrow(ARROW, 0.0)],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 120.0), VIEWPORT, options).unwrap();
+
+    let data = arrow_data(&pos);
+    assert_eq!(data.x, None);
+    assert_eq!(data.y, Some(10.0));
+}
+
+#[test]
+fn padding_keeps_arrow_away_from_corners() {
+    // The 10px reference is centered at x=105, which is 5px into the floating element
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        middleware: vec![arrow(ARROW, 8.0)],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 10.0), VIEWPORT, options).unwrap();
+
+    let data = arrow_data(&pos);
+    assert_eq!(data.x, Some(8.0));
+    assert_eq!(data.center_offset, -8.0);
+}
+
+#[test]
+fn follows_floating_element_after_shift() {
+    // Centered at x=930 overflows by 10px, so shift moves the floating element to x=920
+    // The reference center stays at x=970, so the arrow moves to 970 - 920 - 5 = 45
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Center,
+        middleware: vec![shift(ShiftOptions::default()), arrow(ARROW, 0.0)],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(950.0, 40.0), VIEWPORT, options).unwrap();
+
+    assert_eq!(pos.x, 920.0);
+    assert_eq!(arrow_data(&pos).x, Some(45.0));
+}
+
+#[test]
+fn unmounted_arrow_node_produces_no_data() {
+    let options = FloatingOptions {
+        middleware: vec![arrow(
+            leptos::prelude::NodeRef::<leptos::html::Div>::new(),
+            0.0,
+        )],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 120.0), VIEWPORT, options).unwrap();
+
+    assert!(pos.middleware_data.get::<ArrowData>().is_none());
+}
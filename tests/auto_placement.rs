@@ -0,0 +1,125 @@
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+fn rects(reference_x: f64, reference_y: f64) -> ElementRects {
+    ElementRects {
+        reference: Rect {
+            x: reference_x,
+            y: reference_y,
+            width: 100.0,
+            height: 40.0,
+        },
+        floating: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 80.0,
+            height: 30.0,
+        },
+    }
+}
+
+fn position(reference_x: f64, reference_y: f64, options: AutoPlacementOptions) -> FloatingPosition {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![auto_placement(options)],
+        ..Default::default()
+    };
+
+    calculate_position_from_rects(rects(reference_x, reference_y), VIEWPORT, options).unwrap()
+}
+
+#[test]
+fn picks_side_with_most_space() {
+    // In the bottom-right corner, the left side of the reference has the most room
+    let pos = position(850.0, 700.0, AutoPlacementOptions::default());
+
+    assert_eq!(pos.side, Side::Left);
+    assert_eq!(pos.align, Align::Start);
+}
+
+#[test]
+fn evaluates_every_side_and_alignment() {
+    let pos = position(850.0, 700.0, AutoPlacementOptions::default());
+
+    let data = pos.middleware_data.get::<AutoPlacementData>().unwrap();
+    assert_eq!(data.overflows.len(), 12);
+}
+
+#[test]
+fn only_chooses_from_allowed_placements() {
+    let pos = position(
+        850.0,
+        700.0,
+        AutoPlacementOptions {
+            allowed_placements: Some(vec![
+                Placement {
+                    side: Side::Top,
+                    align: Align::Center,
+                },
+                Placement {
+                    side: Side::Bottom,
+                    align: Align::Center,
+                },
+            ]),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(pos.side, Side::Top);
+    assert_eq!(pos.align, Align::Center);
+    assert_eq!(
+        pos.middleware_data
+            .get::<AutoPlacementData>()
+            .unwrap()
+            .overflows
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn prefers_requested_alignment() {
+    // Near the top-left corner the right side has the most room, for every alignment
+    let pos = position(
+        100.0,
+        100.0,
+        AutoPlacementOptions {
+            alignment: Some(Align::End),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(pos.side, Side::Right);
+    assert_eq!(pos.align, Align::End);
+    assert_eq!(pos.x, 200.0);
+    assert_eq!(pos.y, 110.0);
+}
+
+#[test]
+fn alignment_without_auto_alignment_excludes_other_alignments() {
+    let pos = position(
+        100.0,
+        100.0,
+        AutoPlacementOptions {
+            alignment: Some(Align::Center),
+            auto_alignment: false,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(pos.align, Align::Center);
+    assert_eq!(
+        pos.middleware_data
+            .get::<AutoPlacementData>()
+            .unwrap()
+            .overflows
+            .len(),
+        4
+    );
+}
@@ -0,0 +1,70 @@
+use leptos_floating::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn auto_update_is_opt_in() {
+    assert_eq!(FloatingOptions::default().auto_update, None);
+    assert_eq!(
+        AutoUpdateOptions::default(),
+        AutoUpdateOptions {
+            ancestor_scroll: true,
+            ancestor_resize: true,
+            element_resize: true,
+            layout_shift: true,
+            animation_frame: false,
+        }
+    );
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[cfg_attr(not(target_family = "wasm"), ignore)]
+fn scrolling_an_overflow_ancestor_calls_update_until_cleanup() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use leptos::web_sys::*;
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+
+    let container = document.create_element("div").unwrap();
+    container
+        .set_attribute("style", "overflow: auto; height: 100px")
+        .unwrap();
+    let reference = document.create_element("button").unwrap();
+    let floating = document.create_element("div").unwrap();
+    container.append_child(&reference).unwrap();
+    body.append_child(&container).unwrap();
+    body.append_child(&floating).unwrap();
+
+    let calls = Rc::new(Cell::new(0));
+    let cleanup = auto_update(
+        &reference,
+        &floating,
+        {
+            let calls = Rc::clone(&calls);
+            move || calls.set(calls.get() + 1)
+        },
+        AutoUpdateOptions {
+            element_resize: false,
+            layout_shift: false,
+            ..Default::default()
+        },
+    );
+
+    container
+        .dispatch_event(&Event::new("scroll").unwrap())
+        .unwrap();
+    assert_eq!(calls.get(), 1);
+
+    drop(cleanup);
+    container
+        .dispatch_event(&Event::new("scroll").unwrap())
+        .unwrap();
+    assert_eq!(calls.get(), 1);
+
+    body.remove_child(&container).unwrap();
+    body.remove_child(&floating).unwrap();
+}
@@ -0,0 +1,135 @@
+use leptos_floating::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test(unsupported = test)]
+#[cfg_attr(not(target_family = "wasm"), ignore)]
+fn clipping_rect_is_limited_by_overflow_ancestors() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+
+    let container = document.create_element("div").unwrap();
+    container
+        .set_attribute(
+            "style",
+            "position: absolute; left: 20px; top: 30px; width: 200px; height: 100px; \
+             overflow: hidden",
+        )
+        .unwrap();
+    let floating = document.create_element("div").unwrap();
+    floating
+        .set_attribute("style", "position: absolute")
+        .unwrap();
+    container.append_child(&floating).unwrap();
+    body.append_child(&container).unwrap();
+
+    assert_eq!(clipping_ancestors(&floating), vec![container.clone()]);
+    assert_eq!(
+        get_clipping_rect(
+            &floating,
+            &Boundary::ClippingAncestors,
+            &RootBoundary::Viewport
+        ),
+        Rect {
+            x: 20.0,
+            y: 30.0,
+            width: 200.0,
+            height: 100.0,
+        }
+    );
+
+    body.remove_child(&container).unwrap();
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[cfg_attr(not(target_family = "wasm"), ignore)]
+fn fixed_elements_escape_ancestors_unless_contained() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+
+    let container = document.create_element("div").unwrap();
+    container
+        .set_attribute("style", "overflow: hidden; height: 100px")
+        .unwrap();
+    let floating = document.create_element("div").unwrap();
+    floating.set_attribute("style", "position: fixed").unwrap();
+    container.append_child(&floating).unwrap();
+    body.append_child(&container).unwrap();
+
+    assert!(clipping_ancestors(&floating).is_empty());
+
+    container
+        .set_attribute(
+            "style",
+            "overflow: hidden; height: 100px; transform: scale(1)",
+        )
+        .unwrap();
+    assert_eq!(clipping_ancestors(&floating), vec![container.clone()]);
+
+    body.remove_child(&container).unwrap();
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[cfg_attr(not(target_family = "wasm"), ignore)]
+fn body_is_not_a_clipping_ancestor() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+    let root = document.document_element().unwrap();
+    body.set_attribute("style", "overflow: hidden; height: 50px")
+        .unwrap();
+
+    let floating = document.create_element("div").unwrap();
+    floating
+        .set_attribute("style", "position: absolute")
+        .unwrap();
+    body.append_child(&floating).unwrap();
+
+    assert!(clipping_ancestors(&floating).is_empty());
+    assert_eq!(
+        get_clipping_rect(
+            &floating,
+            &Boundary::ClippingAncestors,
+            &RootBoundary::Viewport
+        ),
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: root.client_width().into(),
+            height: root.client_height().into(),
+        }
+    );
+
+    body.remove_child(&floating).unwrap();
+    body.remove_attribute("style").unwrap();
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+#[cfg_attr(not(target_family = "wasm"), ignore)]
+fn static_ancestors_above_a_fixed_ancestor_do_not_clip() {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+
+    let container = document.create_element("div").unwrap();
+    container
+        .set_attribute("style", "overflow: hidden; height: 100px")
+        .unwrap();
+    let fixed = document.create_element("div").unwrap();
+    fixed.set_attribute("style", "position: fixed").unwrap();
+    let floating = document.create_element("div").unwrap();
+    floating
+        .set_attribute("style", "position: absolute")
+        .unwrap();
+    fixed.append_child(&floating).unwrap();
+    container.append_child(&fixed).unwrap();
+    body.append_child(&container).unwrap();
+
+    assert!(clipping_ancestors(&floating).is_empty());
+
+    fixed
+        .set_attribute("style", "position: fixed; overflow: hidden")
+        .unwrap();
+    assert_eq!(clipping_ancestors(&floating), vec![fixed.clone()]);
+
+    body.remove_child(&container).unwrap();
+}
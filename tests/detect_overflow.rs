@@ -0,0 +1,231 @@
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+const RECTS: ElementRects = ElementRects {
+    reference: Rect {
+        x: 100.0,
+        y: 200.0,
+        width: 120.0,
+        height: 40.0,
+    },
+    floating: Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 80.0,
+        height: 30.0,
+    },
+};
+
+#[derive(Debug, PartialEq)]
+struct Overflow(SideObject);
+
+/// Custom middleware recording the overflow for the given options
+#[derive(Clone)]
+struct RecordOverflow(DetectOverflowOptions);
+
+impl Middleware for RecordOverflow {
+    fn name(&self) -> &'static str {
+        "recordOverflow"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        MiddlewareReturn {
+            data: MiddlewareData::default().with(Overflow(detect_overflow(state, self.0))),
+            ..Default::default()
+        }
+    }
+}
+
+fn overflow(options: DetectOverflowOptions) -> SideObject {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![Box::new(RecordOverflow(options))],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(RECTS, VIEWPORT, options).unwrap();
+    pos.middleware_data.get::<Overflow>().unwrap().0
+}
+
+#[test]
+fn floating_overflow_against_viewport() {
+    // The floating element is placed at (100, 240) with a size of 80x30
+    assert_eq!(
+        overflow(DetectOverflowOptions::default()),
+        SideObject {
+            top: -240.0,
+            right: -820.0,
+            bottom: -530.0,
+            left: -100.0,
+        }
+    );
+}
+
+#[test]
+fn reference_element_context() {
+    let result = overflow(DetectOverflowOptions {
+        element_context: ElementContext::Reference,
+        ..Default::default()
+    });
+
+    assert_eq!(result.top, -200.0);
+    assert_eq!(result.left, -100.0);
+    assert_eq!(result.bottom, -560.0);
+}
+
+#[test]
+fn padding_shrinks_boundary() {
+    let result = overflow(DetectOverflowOptions {
+        padding: 10.0,
+        ..Default::default()
+    });
+
+    assert_eq!(result.top, -230.0);
+    assert_eq!(result.right, -810.0);
+}
+
+#[test]
+fn custom_boundary_is_intersected_with_root_boundary() {
+    // The boundary extends past the viewport on the left, the viewport still clips there
+    let result = overflow(DetectOverflowOptions {
+        boundary: Boundary::Rect(Rect {
+            x: -500.0,
+            y: 250.0,
+            width: 650.0,
+            height: 100.0,
+        }),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        result,
+        SideObject {
+            top: 10.0,
+            right: 30.0,
+            bottom: -80.0,
+            left: -100.0,
+        }
+    );
+}
+
+#[test]
+fn custom_root_boundary() {
+    let result = overflow(DetectOverflowOptions {
+        root_boundary: RootBoundary::Rect(Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 150.0,
+            height: 250.0,
+        }),
+        ..Default::default()
+    });
+
+    assert_eq!(result.right, 30.0);
+    assert_eq!(result.bottom, 20.0);
+}
+
+#[test]
+fn overflow_from_rect_without_pipeline() {
+    let element = Rect {
+        x: 950.0,
+        y: -10.0,
+        width: 80.0,
+        height: 30.0,
+    };
+
+    let result = detect_overflow_from_rect(element, VIEWPORT, 0.0);
+
+    assert_eq!(result.top, 10.0);
+    assert_eq!(result.right, 30.0);
+}
+
+/// State with the floating element placed at (100, 240), clipped by the given rects
+fn clipped_state(clipping_rect: Rect, reference_clipping_rect: Rect) -> MiddlewareState<'static> {
+    MiddlewareState {
+        x: 100.0,
+        y: 240.0,
+        side: Side::Bottom,
+        align: Align::Start,
+        initial_side: Side::Bottom,
+        initial_align: Align::Start,
+        side_offset: 0.0,
+        align_offset: 0.0,
+        rects: RECTS,
+        rtl: false,
+        reference_client_rects: Vec::new(),
+        clipping_rect,
+        reference_clipping_rect,
+        viewport: VIEWPORT,
+        middleware_data: MiddlewareData::default(),
+        platform: &DomPlatform,
+    }
+}
+
+#[test]
+fn alt_boundary_checks_against_the_reference_clipping_ancestors() {
+    // The reference is clipped by a container ending at y=250, the floating element is not
+    let state = clipped_state(
+        VIEWPORT,
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 500.0,
+            height: 250.0,
+        },
+    );
+
+    let own = detect_overflow(&state, DetectOverflowOptions::default());
+    let alt = detect_overflow(
+        &state,
+        DetectOverflowOptions {
+            alt_boundary: true,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(own.bottom, -530.0);
+    assert_eq!(alt.bottom, 20.0);
+}
+
+#[test]
+fn custom_boundary_ignores_clipping_ancestors() {
+    // The floating element's ancestors clip at 150px, the custom boundary replaces them
+    let state = clipped_state(
+        Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 150.0,
+            height: 150.0,
+        },
+        VIEWPORT,
+    );
+
+    let result = detect_overflow(
+        &state,
+        DetectOverflowOptions {
+            boundary: Boundary::Rect(Rect {
+                x: -500.0,
+                y: 0.0,
+                width: 2000.0,
+                height: 300.0,
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        result,
+        SideObject {
+            top: -240.0,
+            right: -820.0,
+            bottom: -30.0,
+            left: -100.0,
+        }
+    );
+}
@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+use leptos::{html, svg};
+use leptos_floating::*;
+
+// Only checks the signatures, positioning real elements needs a browser
+
+#[test]
+fn use_floating_accepts_any_html_element() {
+    fn link_with_list(
+        reference: NodeRef<html::A>,
+        floating: NodeRef<html::Ul>,
+    ) -> UseFloatingReturn {
+        use_floating(reference, floating, FloatingOptions::default())
+    }
+    fn input_with_span(
+        reference: NodeRef<html::Input>,
+        floating: NodeRef<html::Span>,
+    ) -> UseFloatingReturn {
+        use_floating(reference, floating, FloatingOptions::default())
+    }
+
+    let _ = (link_with_list, input_with_span);
+}
+
+#[test]
+fn use_floating_accepts_svg_elements() {
+    fn circle_with_svg(
+        reference: NodeRef<svg::Circle>,
+        floating: NodeRef<svg::Svg>,
+    ) -> UseFloatingReturn {
+        use_floating(reference, floating, FloatingOptions::default())
+    }
+
+    let _ = circle_with_svg;
+}
+
+#[test]
+fn arrow_accepts_any_html_or_svg_element() {
+    fn span_arrow(element: NodeRef<html::Span>) -> Box<dyn Middleware> {
+        arrow(element, 4.0)
+    }
+    fn svg_arrow(element: NodeRef<svg::Svg>) -> Box<dyn Middleware> {
+        arrow(element, 4.0)
+    }
+
+    let _ = (span_arrow, svg_arrow);
+}
@@ -0,0 +1,178 @@
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+fn rects(reference_x: f64, reference_y: f64) -> ElementRects {
+    ElementRects {
+        reference: Rect {
+            x: reference_x,
+            y: reference_y,
+            width: 120.0,
+            height: 40.0,
+        },
+        floating: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 80.0,
+            height: 30.0,
+        },
+    }
+}
+
+#[test]
+fn keeps_side_when_floating_fits() {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions::default())],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 200.0), VIEWPORT, options).unwrap();
+
+    assert_eq!(pos.side, Side::Bottom);
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 240.0);
+}
+
+#[test]
+fn flips_to_opposite_side_on_main_axis_overflow() {
+    // Bottom would place the floating element at y=790, overflowing the viewport by 20px
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions::default())],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 750.0), VIEWPORT, options).unwrap();
+
+    assert_eq!(pos.side, Side::Top);
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 720.0);
+}
+
+#[test]
+fn flips_alignment_on_cross_axis_overflow() {
+    // Start alignment at x=950 overflows the right edge by 30px, End alignment fits
+    let mut element_rects = rects(950.0, 200.0);
+    element_rects.reference.width = 40.0;
+
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        middleware: vec![flip(FlipOptions::default())],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(element_rects, VIEWPORT, options).unwrap();
+
+    assert_eq!(pos.side, Side::Bottom);
+    assert_eq!(pos.align, Align::End);
+    assert_eq!(pos.x, 910.0);
+}
+
+#[test]
+fn tries_configured_fallback_placements() {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions {
+            fallback_placements: Some(vec![Placement {
+                side: Side::Right,
+                align: Align::Start,
+            }]),
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 750.0), VIEWPORT, options).unwrap();
+
+    assert_eq!(pos.side, Side::Right);
+    assert_eq!(pos.x, 220.0);
+}
+
+#[test]
+fn padding_triggers_flip_before_touching_the_edge() {
+    // Bottom ends at y=270 + 30 = 300, inside the viewport but within the padding
+    let clipping_rect = Rect {
+        height: 310.0,
+        ..VIEWPORT
+    };
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions {
+            padding: 20.0,
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, 230.0), clipping_rect, options).unwrap();
+
+    assert_eq!(pos.side, Side::Top);
+}
+
+#[test]
+fn initial_placement_strategy_when_nothing_fits() {
+    // The viewport is shorter than the floating element, so every placement overflows
+    let clipping_rect = Rect {
+        height: 20.0,
+        ..VIEWPORT
+    };
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions {
+            fallback_strategy: FallbackStrategy::InitialPlacement,
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(100.0, -10.0), clipping_rect, options).unwrap();
+
+    assert_eq!(pos.side, Side::Bottom);
+}
+
+#[test]
+fn best_fit_strategy_picks_least_overflow() {
+    // Bottom overflows the 100px tall viewport by 70px, Top by less
+    let clipping_rect = Rect {
+        height: 100.0,
+        ..VIEWPORT
+    };
+    let mut element_rects = rects(100.0, 40.0);
+    element_rects.floating.height = 90.0;
+
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Center,
+        middleware: vec![flip(FlipOptions {
+            cross_axis: false,
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(element_rects, clipping_rect, options).unwrap();
+
+    assert_eq!(pos.side, Side::Top);
+}
+
+#[test]
+fn unbounded_rect_calculation_never_flips() {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions::default())],
+        ..Default::default()
+    };
+
+    let pos =
+        calculate_position_from_rect(100.0, 5000.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    assert_eq!(pos.side, Side::Bottom);
+    assert_eq!(pos.y, 5040.0);
+}
@@ -52,6 +52,7 @@ fn test_use_floating_with_real_dom_elements() {
         align: Align::Start,
         side_offset: 4.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     // Test the actual positioning calculation with real DOM element
@@ -80,6 +81,7 @@ fn test_calculate_position_from_rect_in_browser() {
         align: Align::Start,
         side_offset: 4.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     // floating_width=80, floating_height=30 (not used for Align::Start)
@@ -91,3 +93,46 @@ fn test_calculate_position_from_rect_in_browser() {
     assert_eq!(position.side, Side::Bottom);
     assert_eq!(position.align, Align::Start);
 }
+
+#[wasm_bindgen_test(unsupported = test)]
+#[cfg_attr(not(target_family = "wasm"), ignore)]
+fn test_calculate_position_with_virtual_reference() {
+    use leptos::wasm_bindgen::JsCast;
+    use leptos::web_sys::*;
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let body = document.body().unwrap();
+
+    let div = document
+        .create_element("div")
+        .unwrap()
+        .dyn_into::<HtmlDivElement>()
+        .unwrap();
+    let div_element: &HtmlElement = div.as_ref();
+    let div_style = div_element.style();
+    div_style.set_property("position", "absolute").unwrap();
+    div_style.set_property("width", "80px").unwrap();
+    div_style.set_property("height", "30px").unwrap();
+    body.append_child(&div).unwrap();
+
+    // A reference with no element, e.g. the mouse position
+    let reference = VirtualElement::new(|| Rect {
+        x: 100.0,
+        y: 200.0,
+        width: 120.0,
+        height: 40.0,
+    });
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        side_offset: 4.0,
+        ..Default::default()
+    };
+
+    let position = calculate_position(reference, &div, options).unwrap();
+
+    assert_eq!(position.x, 100.0);
+    assert_eq!(position.y, 244.0);
+
+    body.remove_child(&div).unwrap();
+}
@@ -0,0 +1,90 @@
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+fn rects(reference_y: f64) -> ElementRects {
+    ElementRects {
+        reference: Rect {
+            x: 100.0,
+            y: reference_y,
+            width: 120.0,
+            height: 40.0,
+        },
+        floating: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 80.0,
+            height: 30.0,
+        },
+    }
+}
+
+fn hide_data(reference_y: f64, strategies: &[HideStrategy]) -> HideData {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: strategies
+            .iter()
+            .map(|strategy| {
+                hide(HideOptions {
+                    strategy: *strategy,
+                    ..Default::default()
+                })
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rects(rects(reference_y), VIEWPORT, options).unwrap();
+    *pos.middleware_data.get::<HideData>().unwrap()
+}
+
+#[test]
+fn visible_reference_is_not_hidden() {
+    let data = hide_data(200.0, &[HideStrategy::ReferenceHidden]);
+
+    assert_eq!(data.reference_hidden, Some(false));
+    assert_eq!(data.escaped, None);
+}
+
+#[test]
+fn partially_clipped_reference_is_not_hidden() {
+    // The reference spans y=-20..20, half of it is still visible
+    let data = hide_data(-20.0, &[HideStrategy::ReferenceHidden]);
+
+    assert_eq!(data.reference_hidden, Some(false));
+}
+
+#[test]
+fn reference_scrolled_out_of_view_is_hidden() {
+    // The reference spans y=-100..-60, fully above the viewport
+    let data = hide_data(-100.0, &[HideStrategy::ReferenceHidden]);
+
+    assert_eq!(data.reference_hidden, Some(true));
+    assert_eq!(data.reference_hidden_offsets.unwrap().top, 60.0);
+}
+
+#[test]
+fn floating_escaped_past_the_boundary() {
+    // The floating element is placed at y=840, fully below the viewport
+    let data = hide_data(800.0, &[HideStrategy::Escaped]);
+
+    assert_eq!(data.escaped, Some(true));
+    assert_eq!(data.reference_hidden, None);
+}
+
+#[test]
+fn both_strategies_are_reported_together() {
+    let data = hide_data(
+        -100.0,
+        &[HideStrategy::ReferenceHidden, HideStrategy::Escaped],
+    );
+
+    // The floating element sits at y=-60..-30, also fully above the viewport
+    assert_eq!(data.reference_hidden, Some(true));
+    assert_eq!(data.escaped, Some(true));
+}
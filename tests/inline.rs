@@ -0,0 +1,189 @@
+use leptos_floating::*;
+
+// A link wrapping from the end of one line to the start of the next
+const FIRST_LINE: Rect = Rect {
+    x: 300.0,
+    y: 100.0,
+    width: 200.0,
+    height: 20.0,
+};
+const SECOND_LINE: Rect = Rect {
+    x: 0.0,
+    y: 120.0,
+    width: 150.0,
+    height: 20.0,
+};
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+fn state(side: Side, client_rects: Vec<Rect>) -> MiddlewareState<'static> {
+    MiddlewareState {
+        x: 0.0,
+        y: 0.0,
+        side,
+        align: Align::Start,
+        initial_side: side,
+        initial_align: Align::Start,
+        side_offset: 0.0,
+        align_offset: 0.0,
+        rects: ElementRects {
+            // Bounding box around both lines
+            reference: Rect {
+                x: 0.0,
+                y: 100.0,
+                width: 500.0,
+                height: 40.0,
+            },
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 80.0,
+                height: 30.0,
+            },
+        },
+        rtl: false,
+        reference_client_rects: client_rects,
+        clipping_rect: VIEWPORT,
+        reference_clipping_rect: VIEWPORT,
+        viewport: VIEWPORT,
+        middleware_data: MiddlewareData::default(),
+        platform: &DomPlatform,
+    }
+}
+
+fn reset_reference(options: InlineOptions, state: &MiddlewareState) -> Option<Rect> {
+    inline(options)
+        .compute(state)
+        .reset
+        .and_then(|reset| reset.rects)
+        .map(|rects| rects.reference)
+}
+
+#[test]
+fn bottom_anchors_to_last_line() {
+    let state = state(Side::Bottom, vec![FIRST_LINE, SECOND_LINE]);
+
+    assert_eq!(
+        reset_reference(InlineOptions::default(), &state),
+        Some(Rect {
+            x: 0.0,
+            y: 100.0,
+            width: 150.0,
+            height: 40.0,
+        })
+    );
+}
+
+#[test]
+fn top_anchors_to_first_line() {
+    let state = state(Side::Top, vec![FIRST_LINE, SECOND_LINE]);
+
+    assert_eq!(
+        reset_reference(InlineOptions::default(), &state),
+        Some(Rect {
+            x: 300.0,
+            y: 100.0,
+            width: 200.0,
+            height: 40.0,
+        })
+    );
+}
+
+#[test]
+fn right_spans_lines_reaching_furthest_right() {
+    let state = state(Side::Right, vec![FIRST_LINE, SECOND_LINE]);
+
+    assert_eq!(
+        reset_reference(InlineOptions::default(), &state),
+        Some(Rect {
+            x: 0.0,
+            y: 100.0,
+            width: 500.0,
+            height: 20.0,
+        })
+    );
+}
+
+#[test]
+fn pointer_picks_hovered_line() {
+    let state = state(Side::Bottom, vec![FIRST_LINE, SECOND_LINE]);
+    let options = InlineOptions {
+        x: Some(50.0),
+        y: Some(130.0),
+        ..Default::default()
+    };
+
+    assert_eq!(reset_reference(options, &state), Some(SECOND_LINE));
+}
+
+#[test]
+fn rects_on_the_same_line_are_merged() {
+    // Two fragments of a single line, e.g. text with a nested <strong>
+    let fragments = vec![
+        Rect {
+            x: 0.0,
+            y: 100.0,
+            width: 50.0,
+            height: 20.0,
+        },
+        Rect {
+            x: 50.0,
+            y: 102.0,
+            width: 60.0,
+            height: 18.0,
+        },
+    ];
+    let state = state(Side::Bottom, fragments);
+
+    assert_eq!(reset_reference(InlineOptions::default(), &state), None);
+}
+
+#[test]
+fn no_client_rects_keeps_position() {
+    let options = FloatingOptions {
+        middleware: vec![inline(InlineOptions::default())],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 240.0);
+}
+
+#[test]
+fn reset_moves_floating_to_chosen_line() {
+    #[derive(Clone)]
+    struct WithClientRects;
+
+    // Runs the inline middleware against a state that carries client rects
+    impl Middleware for WithClientRects {
+        fn name(&self) -> &'static str {
+            "withClientRects"
+        }
+
+        fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+            let state = MiddlewareState {
+                reference_client_rects: vec![FIRST_LINE, SECOND_LINE],
+                ..state.clone()
+            };
+            inline(InlineOptions::default()).compute(&state)
+        }
+    }
+
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![Box::new(WithClientRects)],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rect(0.0, 100.0, 500.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    assert_eq!(pos.x, 0.0);
+    assert_eq!(pos.y, 140.0);
+}
@@ -0,0 +1,178 @@
+use leptos_floating::*;
+
+#[derive(Clone)]
+struct Nudge {
+    dx: f64,
+    dy: f64,
+}
+
+impl Middleware for Nudge {
+    fn name(&self) -> &'static str {
+        "nudge"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        MiddlewareReturn {
+            x: Some(state.x + self.dx),
+            y: Some(state.y + self.dy),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct SeenX(f64);
+
+#[derive(Clone)]
+struct RecordX;
+
+impl Middleware for RecordX {
+    fn name(&self) -> &'static str {
+        "recordX"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        MiddlewareReturn {
+            data: MiddlewareData::default().with(SeenX(state.x)),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ResetTo(Side);
+
+impl Middleware for ResetTo {
+    fn name(&self) -> &'static str {
+        "resetTo"
+    }
+
+    fn compute(&self, state: &MiddlewareState) -> MiddlewareReturn {
+        if state.side == self.0 {
+            return MiddlewareReturn::default();
+        }
+
+        MiddlewareReturn {
+            reset: Some(Reset {
+                side: Some(self.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone)]
+struct AlwaysReset;
+
+impl Middleware for AlwaysReset {
+    fn name(&self) -> &'static str {
+        "alwaysReset"
+    }
+
+    fn compute(&self, _state: &MiddlewareState) -> MiddlewareReturn {
+        MiddlewareReturn {
+            reset: Some(Reset::default()),
+            ..Default::default()
+        }
+    }
+}
+
+#[test]
+fn no_middleware_keeps_base_position() {
+    let pos = calculate_position_from_rect(
+        100.0,
+        200.0,
+        120.0,
+        40.0,
+        80.0,
+        30.0,
+        FloatingOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 240.0);
+    assert!(pos.middleware_data.is_empty());
+}
+
+#[test]
+fn middleware_modifies_coordinates() {
+    let options = FloatingOptions {
+        middleware: vec![Box::new(Nudge { dx: 5.0, dy: -3.0 })],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    assert_eq!(pos.x, 105.0);
+    assert_eq!(pos.y, 237.0);
+}
+
+#[test]
+fn middleware_run_in_order_over_shared_state() {
+    let options = FloatingOptions {
+        middleware: vec![
+            Box::new(Nudge { dx: 5.0, dy: 0.0 }),
+            Box::new(RecordX),
+            Box::new(Nudge { dx: 5.0, dy: 0.0 }),
+        ],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    assert_eq!(
+        pos.middleware_data.get::<SeenX>(),
+        Some(&SeenX(105.0)),
+        "RecordX should see the output of the first middleware only"
+    );
+    assert_eq!(pos.x, 110.0);
+}
+
+#[test]
+fn reset_recomputes_coordinates_for_new_side() {
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        side_offset: 4.0,
+        middleware: vec![
+            Box::new(Nudge { dx: 1.0, dy: 0.0 }),
+            Box::new(ResetTo(Side::Right)),
+        ],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    // Right: 100 + 120 + 4 = 224, then Nudge runs again after the reset
+    assert_eq!(pos.side, Side::Right);
+    assert_eq!(pos.x, 225.0);
+    assert_eq!(pos.y, 200.0);
+}
+
+#[test]
+fn endless_resets_are_bounded() {
+    let options = FloatingOptions {
+        middleware: vec![Box::new(AlwaysReset)],
+        ..Default::default()
+    };
+
+    let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
+
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 240.0);
+}
+
+#[test]
+fn options_with_middleware_are_cloneable() {
+    let options = FloatingOptions {
+        middleware: vec![Box::new(Nudge { dx: 1.0, dy: 1.0 })],
+        ..Default::default()
+    };
+
+    let cloned = options.clone();
+
+    assert_eq!(cloned.middleware.len(), 1);
+    assert_eq!(cloned.middleware[0].name(), "nudge");
+    assert!(format!("{cloned:?}").contains("nudge"));
+}
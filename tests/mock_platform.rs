@@ -0,0 +1,580 @@
+#![cfg(feature = "testing")]
+
+use leptos::prelude::*;
+use leptos_floating::testing::{MockElement, MockPlatform, flush_effects, init_executor};
+use leptos_floating::*;
+
+const REFERENCE: Rect = Rect {
+    x: 100.0,
+    y: 200.0,
+    width: 120.0,
+    height: 40.0,
+};
+
+const FLOATING: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 80.0,
+    height: 30.0,
+};
+
+fn setup() -> (MockPlatform, MockElement, MockElement) {
+    init_executor();
+    Owner::new().set();
+
+    let platform = MockPlatform::default();
+    let reference = platform.element(REFERENCE);
+    let floating = platform.element(FLOATING);
+    (platform, reference, floating)
+}
+
+#[test]
+fn positions_floating_element() {
+    let (platform, reference, floating) = setup();
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+
+    assert_eq!(position.x.get_untracked(), 100.0);
+    assert_eq!(position.y.get_untracked(), 240.0);
+}
+
+#[test]
+fn waits_for_both_elements() {
+    let (platform, reference, floating) = setup();
+    let mounted = RwSignal::new(false);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || mounted.get().then_some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+    assert_eq!(position.y.get_untracked(), 0.0);
+
+    mounted.set(true);
+    flush_effects();
+    assert_eq!(position.y.get_untracked(), 240.0);
+}
+
+#[test]
+fn middleware_are_clipped_by_ancestors() {
+    // The reference sits near the bottom of a 250px tall container that clips the floating element
+    let (platform, reference, floating) = setup();
+    let container = platform.element(Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 500.0,
+        height: 250.0,
+    });
+    platform.set_clips(container, true);
+    platform.set_parent(floating, container);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            middleware: vec![flip(FlipOptions::default())],
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    assert_eq!(position.side.get_untracked(), Side::Top);
+    assert_eq!(position.y.get_untracked(), 170.0);
+}
+
+#[test]
+fn auto_update_follows_scroll_container() {
+    let (platform, reference, floating) = setup();
+    let container = platform.element(Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 500.0,
+        height: 500.0,
+    });
+    platform.set_scroll_container(container);
+    platform.set_parent(reference, container);
+
+    let position = use_floating_with_platform(
+        platform.clone(),
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            auto_update: Some(AutoUpdateOptions::default()),
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    platform.scroll_to(container, Coords { x: 0.0, y: 50.0 });
+    assert_eq!(position.y.get_untracked(), 190.0);
+}
+
+#[test]
+fn auto_update_follows_layout_shift() {
+    let (platform, reference, floating) = setup();
+
+    let position = use_floating_with_platform(
+        platform.clone(),
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            auto_update: Some(AutoUpdateOptions::default()),
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    platform.set_rect(
+        reference,
+        Rect {
+            y: 300.0,
+            ..REFERENCE
+        },
+    );
+    assert_eq!(position.y.get_untracked(), 340.0);
+}
+
+#[test]
+fn position_is_stale_without_auto_update() {
+    let (platform, reference, floating) = setup();
+    let container = platform.element(Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 500.0,
+        height: 500.0,
+    });
+    platform.set_scroll_container(container);
+    platform.set_parent(reference, container);
+
+    let position = use_floating_with_platform(
+        platform.clone(),
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+
+    platform.scroll_to(container, Coords { x: 0.0, y: 50.0 });
+    assert_eq!(position.y.get_untracked(), 240.0);
+}
+
+#[test]
+fn virtual_reference_follows_its_signal() {
+    let (platform, _, floating) = setup();
+    let cursor = RwSignal::new(Coords { x: 10.0, y: 20.0 });
+    let reference = VirtualElement::new(move || {
+        let cursor = cursor.get();
+        Rect {
+            x: cursor.x,
+            y: cursor.y,
+            width: 0.0,
+            height: 0.0,
+        }
+    });
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(Reference::Virtual(reference.clone())),
+        move || Some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+    assert_eq!(position.x.get_untracked(), 10.0);
+    assert_eq!(position.y.get_untracked(), 20.0);
+
+    cursor.set(Coords { x: 300.0, y: 400.0 });
+    flush_effects();
+    assert_eq!(position.x.get_untracked(), 300.0);
+    assert_eq!(position.y.get_untracked(), 400.0);
+}
+
+#[test]
+fn virtual_reference_client_rects_reach_inline() {
+    // A selection spanning two lines, the floating element anchors below the last one
+    let (platform, _, floating) = setup();
+    let first_line = Rect {
+        x: 300.0,
+        y: 100.0,
+        width: 200.0,
+        height: 20.0,
+    };
+    let second_line = Rect {
+        x: 0.0,
+        y: 120.0,
+        width: 150.0,
+        height: 20.0,
+    };
+    let reference = VirtualElement::new(move || Rect {
+        x: 0.0,
+        y: 100.0,
+        width: 500.0,
+        height: 40.0,
+    })
+    .with_client_rects(move || vec![first_line, second_line]);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(Reference::Virtual(reference.clone())),
+        move || Some(floating),
+        FloatingOptions {
+            middleware: vec![inline(InlineOptions::default())],
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    assert_eq!(position.x.get_untracked(), 0.0);
+    assert_eq!(position.y.get_untracked(), 140.0);
+}
+
+#[test]
+fn recalculates_when_options_change() {
+    let (platform, reference, floating) = setup();
+    let options = RwSignal::new(FloatingOptions::default());
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        options,
+    );
+    flush_effects();
+    assert_eq!(position.y.get_untracked(), 240.0);
+
+    options.update(|options| options.side_offset = 8.0);
+    flush_effects();
+    assert_eq!(position.y.get_untracked(), 248.0);
+
+    options.update(|options| options.side = Side::Right);
+    flush_effects();
+    assert_eq!(position.side.get_untracked(), Side::Right);
+    assert_eq!(position.x.get_untracked(), 228.0);
+}
+
+fn positioned_container(platform: &MockPlatform, floating: MockElement) -> MockElement {
+    let container = platform.element(Rect {
+        x: 50.0,
+        y: 60.0,
+        width: 600.0,
+        height: 600.0,
+    });
+    platform.set_parent(floating, container);
+    platform.set_offset_parent(floating, Some(container));
+    container
+}
+
+#[test]
+fn absolute_coordinates_are_relative_to_offset_parent() {
+    let (platform, reference, floating) = setup();
+    positioned_container(&platform, floating);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+
+    assert_eq!(position.strategy.get_untracked(), Strategy::Absolute);
+    assert_eq!(position.x.get_untracked(), 50.0);
+    assert_eq!(position.y.get_untracked(), 180.0);
+}
+
+#[test]
+fn absolute_coordinates_include_offset_parent_scroll() {
+    // Both elements scroll with the container, so the absolute position stays the same
+    let (platform, reference, floating) = setup();
+    let container = positioned_container(&platform, floating);
+    platform.set_scroll_container(container);
+    platform.set_parent(reference, container);
+    platform.scroll_to(container, Coords { x: 0.0, y: 30.0 });
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+
+    assert_eq!(position.x.get_untracked(), 50.0);
+    assert_eq!(position.y.get_untracked(), 180.0);
+}
+
+#[test]
+fn fixed_coordinates_are_relative_to_viewport() {
+    let (platform, reference, floating) = setup();
+    positioned_container(&platform, floating);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            strategy: Strategy::Fixed,
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    assert_eq!(position.strategy.get_untracked(), Strategy::Fixed);
+    assert_eq!(position.x.get_untracked(), 100.0);
+    assert_eq!(position.y.get_untracked(), 240.0);
+}
+
+#[test]
+fn fixed_coordinates_are_relative_to_containing_block() {
+    // A transformed ancestor contains fixed elements like an offset parent does
+    let (platform, reference, floating) = setup();
+    let card = positioned_container(&platform, floating);
+    platform.set_offset_parent(floating, None);
+    platform.set_containing_block(card, true);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            strategy: Strategy::Fixed,
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    assert_eq!(position.x.get_untracked(), 50.0);
+    assert_eq!(position.y.get_untracked(), 180.0);
+}
+
+#[test]
+fn containing_block_closer_than_offset_parent_wins() {
+    let (platform, reference, floating) = setup();
+    let container = positioned_container(&platform, floating);
+    let card = platform.element(Rect {
+        x: 80.0,
+        y: 100.0,
+        width: 400.0,
+        height: 400.0,
+    });
+    platform.set_parent(card, container);
+    platform.set_parent(floating, card);
+    platform.set_containing_block(card, true);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+
+    assert_eq!(position.x.get_untracked(), 20.0);
+    assert_eq!(position.y.get_untracked(), 140.0);
+}
+
+#[test]
+fn floating_styles_are_visible_once_positioned() {
+    let (platform, reference, floating) = setup();
+    let floating_ref = RwSignal::new(None);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || floating_ref.get(),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+    assert_eq!(
+        position.floating_styles.get_untracked(),
+        "position: absolute; left: 0px; top: 0px; visibility: hidden;"
+    );
+
+    floating_ref.set(Some(floating));
+    flush_effects();
+    assert_eq!(
+        position.floating_styles.get_untracked(),
+        "position: absolute; left: 100px; top: 240px;"
+    );
+}
+
+#[test]
+fn coordinates_snap_to_device_pixels() {
+    let (platform, reference, floating) = setup();
+    platform.set_rect(
+        reference,
+        Rect {
+            x: 100.3,
+            y: 200.2,
+            ..REFERENCE
+        },
+    );
+    platform.set_device_pixel_ratio(2.0);
+
+    let options = RwSignal::new(FloatingOptions::default());
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        options,
+    );
+    flush_effects();
+    assert_eq!(position.x.get_untracked(), 100.5);
+    assert_eq!(position.y.get_untracked(), 240.0);
+
+    options.update(|options| options.round_by_dpr = false);
+    flush_effects();
+    assert_eq!(position.x.get_untracked(), 100.3);
+}
+
+#[test]
+fn is_positioned_after_first_calculation_until_unmounted() {
+    let (platform, reference, floating) = setup();
+    let floating_ref = RwSignal::new(None);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || floating_ref.get(),
+        FloatingOptions::default(),
+    );
+    flush_effects();
+    assert!(!position.is_positioned.get_untracked());
+
+    floating_ref.set(Some(floating));
+    flush_effects();
+    assert!(position.is_positioned.get_untracked());
+
+    floating_ref.set(None);
+    flush_effects();
+    assert!(!position.is_positioned.get_untracked());
+}
+
+#[test]
+fn only_positions_while_open() {
+    let (platform, reference, floating) = setup();
+    let container = platform.element(Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 500.0,
+        height: 500.0,
+    });
+    platform.set_scroll_container(container);
+    platform.set_parent(reference, container);
+    let open = RwSignal::new(false);
+
+    let position = use_floating_with_platform(
+        platform.clone(),
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            auto_update: Some(AutoUpdateOptions::default()),
+            open: Some(open.into()),
+            ..Default::default()
+        },
+    );
+    flush_effects();
+    assert!(!position.is_positioned.get_untracked());
+    assert_eq!(position.y.get_untracked(), 0.0);
+
+    open.set(true);
+    flush_effects();
+    assert!(position.is_positioned.get_untracked());
+    assert_eq!(position.y.get_untracked(), 240.0);
+
+    // Closing removes the auto update listeners
+    open.set(false);
+    flush_effects();
+    platform.scroll_to(container, Coords { x: 0.0, y: 50.0 });
+    assert!(!position.is_positioned.get_untracked());
+    assert_eq!(position.y.get_untracked(), 240.0);
+}
+
+#[test]
+fn on_open_change_is_forwarded_to_options() {
+    let (platform, reference, floating) = setup();
+    let open = RwSignal::new(false);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            open: Some(open.into()),
+            on_open_change: Some(Callback::new(move |value| open.set(value))),
+            ..Default::default()
+        },
+    );
+    position.on_open_change.run(true);
+    flush_effects();
+
+    assert!(position.open.get_untracked());
+    assert!(position.is_positioned.get_untracked());
+}
+
+#[test]
+fn hide_measures_the_reference_against_its_own_clipping_ancestors() {
+    // The reference scrolled out of its container while the floating element sits in the body
+    let (platform, reference, floating) = setup();
+    let container = platform.element(Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 500.0,
+        height: 150.0,
+    });
+    platform.set_scroll_container(container);
+    platform.set_parent(reference, container);
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            middleware: vec![
+                hide(HideOptions::default()),
+                hide(HideOptions {
+                    strategy: HideStrategy::Escaped,
+                    ..Default::default()
+                }),
+            ],
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    assert!(position.reference_hidden.get_untracked());
+    assert!(position.escaped.get_untracked());
+}
+
+#[test]
+fn arrow_is_measured_through_the_platform() {
+    let (platform, reference, floating) = setup();
+    let arrow_element = platform.element(Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 10.0,
+        height: 10.0,
+    });
+
+    let position = use_floating_with_platform(
+        platform,
+        move || Some(reference),
+        move || Some(floating),
+        FloatingOptions {
+            middleware: vec![arrow(ArrowElement::element(arrow_element), 0.0)],
+            ..Default::default()
+        },
+    );
+    flush_effects();
+
+    // The reference center at x=160 is 60px into the floating element, minus half the arrow
+    assert_eq!(position.arrow_x.get_untracked(), Some(55.0));
+}
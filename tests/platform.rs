@@ -0,0 +1,254 @@
+use leptos_floating::*;
+
+/// Platform with fixed measurements, elements are identified by name
+struct FixedPlatform {
+    reference: Rect,
+    floating: Dimensions,
+    viewport: Rect,
+    rtl: bool,
+}
+
+impl Default for FixedPlatform {
+    fn default() -> Self {
+        Self {
+            reference: Rect {
+                x: 100.0,
+                y: 200.0,
+                width: 120.0,
+                height: 40.0,
+            },
+            floating: Dimensions {
+                width: 80.0,
+                height: 30.0,
+            },
+            viewport: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 1000.0,
+                height: 800.0,
+            },
+            rtl: false,
+        }
+    }
+}
+
+impl Platform for FixedPlatform {
+    type Element = &'static str;
+
+    fn get_element_rects(&self, _reference: &&str, _floating: &&str) -> ElementRects {
+        ElementRects {
+            reference: self.reference,
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: self.floating.width,
+                height: self.floating.height,
+            },
+        }
+    }
+
+    fn get_clipping_rect(
+        &self,
+        _element: &&str,
+        _boundary: &Boundary,
+        _root_boundary: &RootBoundary,
+    ) -> Rect {
+        self.viewport
+    }
+
+    fn get_dimensions(&self, element: &&str) -> Dimensions {
+        match *element {
+            "floating" => self.floating,
+            _ => Dimensions {
+                width: self.reference.width,
+                height: self.reference.height,
+            },
+        }
+    }
+
+    fn get_offset_parent(&self, _element: &&str, _strategy: Strategy) -> Option<&'static str> {
+        None
+    }
+
+    fn is_rtl(&self, _element: &&str) -> bool {
+        self.rtl
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        rect: Rect,
+        _offset_parent: Option<&&'static str>,
+        _strategy: Strategy,
+    ) -> Rect {
+        rect
+    }
+
+    fn get_scale(&self, _element: &&str) -> Coords {
+        Coords { x: 1.0, y: 1.0 }
+    }
+}
+
+#[test]
+fn positions_from_platform_rects() {
+    let pos = calculate_position_with_platform(
+        &FixedPlatform::default(),
+        "reference",
+        &"floating",
+        FloatingOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 240.0);
+}
+
+#[test]
+fn middleware_see_platform_clipping_rect() {
+    let platform = FixedPlatform {
+        viewport: Rect {
+            height: 250.0,
+            ..FixedPlatform::default().viewport
+        },
+        ..Default::default()
+    };
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![flip(FlipOptions::default())],
+        ..Default::default()
+    };
+
+    let pos =
+        calculate_position_with_platform(&platform, "reference", &"floating", options).unwrap();
+
+    assert_eq!(pos.side, Side::Top);
+}
+
+#[test]
+fn start_aligns_to_the_right_edge_in_rtl() {
+    // The reference's right edge is at x=220, so the 80px wide element starts at x=140
+    let platform = FixedPlatform {
+        rtl: true,
+        ..Default::default()
+    };
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        ..Default::default()
+    };
+
+    let pos =
+        calculate_position_with_platform(&platform, "reference", &"floating", options).unwrap();
+
+    assert_eq!(pos.x, 140.0);
+    assert_eq!(pos.align, Align::Start);
+}
+
+/// RTL platform with a 200px wide floating element, wider than the 120px reference
+fn wide_rtl_platform(viewport_x: f64, viewport_width: f64) -> FixedPlatform {
+    FixedPlatform {
+        floating: Dimensions {
+            width: 200.0,
+            height: 30.0,
+        },
+        viewport: Rect {
+            x: viewport_x,
+            width: viewport_width,
+            ..FixedPlatform::default().viewport
+        },
+        rtl: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn auto_placement_measures_candidates_in_rtl() {
+    // Start spans x=20..220 in RTL and overflows the boundary at x=50, End spans x=100..300
+    let platform = wide_rtl_platform(50.0, 950.0);
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![auto_placement(AutoPlacementOptions {
+            cross_axis: true,
+            allowed_placements: Some(vec![
+                Placement {
+                    side: Side::Bottom,
+                    align: Align::Start,
+                },
+                Placement {
+                    side: Side::Bottom,
+                    align: Align::End,
+                },
+            ]),
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    let pos =
+        calculate_position_with_platform(&platform, "reference", &"floating", options).unwrap();
+
+    assert_eq!(pos.align, Align::End);
+    assert_eq!(pos.x, 100.0);
+}
+
+#[test]
+fn flip_ranks_cross_axis_overflow_in_rtl() {
+    // In the boundary from x=50 to x=250 every placement overflows along the cross axis. In RTL
+    // Start overflows its start edge by 30px and End by 50px, so Start is the best fit
+    let platform = wide_rtl_platform(50.0, 200.0);
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        middleware: vec![flip(FlipOptions::default())],
+        ..Default::default()
+    };
+
+    let pos =
+        calculate_position_with_platform(&platform, "reference", &"floating", options).unwrap();
+
+    assert_eq!(pos.side, Side::Bottom);
+    assert_eq!(pos.align, Align::Start);
+    assert_eq!(pos.x, 20.0);
+}
+
+#[test]
+fn size_grows_end_aligned_elements_to_the_right_in_rtl() {
+    // End aligns to the reference's left edge in RTL, leaving 900px up to the right edge
+    let platform = FixedPlatform {
+        rtl: true,
+        ..Default::default()
+    };
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        align: Align::End,
+        middleware: vec![size(SizeOptions::default())],
+        ..Default::default()
+    };
+
+    let pos =
+        calculate_position_with_platform(&platform, "reference", &"floating", options).unwrap();
+
+    let data = pos.middleware_data.get::<SizeData>().unwrap();
+    assert_eq!(data.available_width, 900.0);
+}
+
+#[test]
+fn positions_next_to_a_virtual_reference() {
+    // The floating element is measured through the platform, the reference by its closure
+    let reference = VirtualElement::new(|| Rect {
+        x: 300.0,
+        y: 100.0,
+        width: 0.0,
+        height: 0.0,
+    });
+
+    let pos = calculate_position_with_platform(
+        &FixedPlatform::default(),
+        Reference::Virtual(reference),
+        &"floating",
+        FloatingOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 300.0);
+    assert_eq!(pos.y, 100.0);
+}
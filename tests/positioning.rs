@@ -10,12 +10,16 @@ fn align_offset_shifts_x_for_top_side() {
         align: Align::Start,
         side_offset: 0.0,
         align_offset: 10.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
     assert_eq!(pos.x, 110.0, "align_offset should shift x for Top side");
-    assert_eq!(pos.y, 200.0, "align_offset should NOT shift y for Top side");
+    assert_eq!(
+        pos.y, 170.0,
+        "align_offset should NOT shift y for Top side (200 - 30)"
+    );
 }
 
 #[test]
@@ -25,6 +29,7 @@ fn align_offset_shifts_x_for_bottom_side() {
         align: Align::Start,
         side_offset: 0.0,
         align_offset: 10.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -44,13 +49,14 @@ fn align_offset_shifts_y_for_left_side() {
         align: Align::Start,
         side_offset: 0.0,
         align_offset: 10.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
     assert_eq!(
-        pos.x, 100.0,
-        "align_offset should NOT shift x for Left side"
+        pos.x, 20.0,
+        "align_offset should NOT shift x for Left side (100 - 80)"
     );
     assert_eq!(pos.y, 210.0, "align_offset should shift y for Left side");
 }
@@ -62,6 +68,7 @@ fn align_offset_shifts_y_for_right_side() {
         align: Align::Start,
         side_offset: 0.0,
         align_offset: 10.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -84,6 +91,7 @@ fn align_center_centers_on_bottom_side() {
         align: Align::Center,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -102,6 +110,7 @@ fn align_center_centers_on_top_side() {
         align: Align::Center,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -110,7 +119,10 @@ fn align_center_centers_on_top_side() {
         pos.x, 120.0,
         "Center align should center floating on reference (100 + (120-80)/2)"
     );
-    assert_eq!(pos.y, 200.0);
+    assert_eq!(
+        pos.y, 170.0,
+        "Top side should sit above the reference (200 - 30)"
+    );
 }
 
 #[test]
@@ -122,6 +134,7 @@ fn align_center_centers_on_right_side() {
         align: Align::Center,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -140,11 +153,15 @@ fn align_center_centers_on_left_side() {
         align: Align::Center,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
-    assert_eq!(pos.x, 100.0);
+    assert_eq!(
+        pos.x, 20.0,
+        "Left side should sit left of the reference (100 - 80)"
+    );
     assert_eq!(
         pos.y, 205.0,
         "Center align should center floating on reference (200 + (40-30)/2)"
@@ -162,6 +179,7 @@ fn align_end_aligns_on_bottom_side() {
         align: Align::End,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -180,6 +198,7 @@ fn align_end_aligns_on_top_side() {
         align: Align::End,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -188,7 +207,10 @@ fn align_end_aligns_on_top_side() {
         pos.x, 140.0,
         "End align should align floating to end of reference (100 + 120 - 80)"
     );
-    assert_eq!(pos.y, 200.0);
+    assert_eq!(
+        pos.y, 170.0,
+        "Top side should sit above the reference (200 - 30)"
+    );
 }
 
 #[test]
@@ -200,6 +222,7 @@ fn align_end_aligns_on_right_side() {
         align: Align::End,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -218,11 +241,15 @@ fn align_end_aligns_on_left_side() {
         align: Align::End,
         side_offset: 0.0,
         align_offset: 0.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
 
-    assert_eq!(pos.x, 100.0);
+    assert_eq!(
+        pos.x, 20.0,
+        "Left side should sit left of the reference (100 - 80)"
+    );
     assert_eq!(
         pos.y, 210.0,
         "End align should align floating to end of reference (200 + 40 - 30)"
@@ -238,6 +265,7 @@ fn align_center_with_align_offset_on_bottom() {
         align: Align::Center,
         side_offset: 0.0,
         align_offset: 5.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
@@ -254,6 +282,7 @@ fn align_end_with_align_offset_on_right() {
         align: Align::End,
         side_offset: 0.0,
         align_offset: 5.0,
+        ..Default::default()
     };
 
     let pos = calculate_position_from_rect(100.0, 200.0, 120.0, 40.0, 80.0, 30.0, options).unwrap();
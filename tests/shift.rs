@@ -0,0 +1,164 @@
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+fn rects(reference_x: f64, reference_y: f64) -> ElementRects {
+    ElementRects {
+        reference: Rect {
+            x: reference_x,
+            y: reference_y,
+            width: 40.0,
+            height: 40.0,
+        },
+        floating: Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 80.0,
+            height: 30.0,
+        },
+    }
+}
+
+fn bottom_start(shift_options: ShiftOptions) -> FloatingOptions {
+    FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        middleware: vec![shift(shift_options)],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn does_not_shift_when_floating_fits() {
+    let pos = calculate_position_from_rects(
+        rects(100.0, 200.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions::default()),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 100.0);
+    assert_eq!(pos.y, 240.0);
+    assert_eq!(
+        pos.middleware_data.get::<ShiftData>(),
+        Some(&ShiftData {
+            x: 0.0,
+            y: 0.0,
+            enabled_x: true,
+            enabled_y: false,
+        })
+    );
+}
+
+#[test]
+fn shifts_back_inside_right_edge() {
+    // Start alignment at x=950 puts the 80px wide element 30px past the right edge
+    let pos = calculate_position_from_rects(
+        rects(950.0, 200.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions::default()),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 920.0);
+    assert_eq!(pos.y, 240.0);
+    assert_eq!(
+        pos.middleware_data.get::<ShiftData>(),
+        Some(&ShiftData {
+            x: -30.0,
+            y: 0.0,
+            enabled_x: true,
+            enabled_y: false,
+        })
+    );
+}
+
+#[test]
+fn shifts_back_inside_left_edge() {
+    let pos = calculate_position_from_rects(
+        rects(-20.0, 200.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions::default()),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 0.0);
+    assert_eq!(pos.middleware_data.get::<ShiftData>().unwrap().x, 20.0);
+}
+
+#[test]
+fn padding_keeps_distance_from_edge() {
+    let pos = calculate_position_from_rects(
+        rects(950.0, 200.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions {
+            padding: 10.0,
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, 910.0);
+}
+
+#[test]
+fn cross_axis_is_only_shifted_when_enabled() {
+    // Bottom places the element at y=820, past the 800px viewport
+    let without = calculate_position_from_rects(
+        rects(100.0, 780.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions::default()),
+    )
+    .unwrap();
+    let with = calculate_position_from_rects(
+        rects(100.0, 780.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions {
+            cross_axis: true,
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(without.y, 820.0);
+    assert_eq!(with.y, 770.0);
+}
+
+#[test]
+fn limiter_keeps_floating_attached_to_reference() {
+    // The reference spans x=-200..-160, so shifting fully into view would detach the element
+    let pos = calculate_position_from_rects(
+        rects(-200.0, 200.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions {
+            limiter: Some(LimitShift::default()),
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, -160.0);
+}
+
+#[test]
+fn limiter_offset_stops_shifting_earlier() {
+    let pos = calculate_position_from_rects(
+        rects(-200.0, 200.0),
+        VIEWPORT,
+        bottom_start(ShiftOptions {
+            limiter: Some(LimitShift {
+                offset: 10.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(pos.x, -170.0);
+}
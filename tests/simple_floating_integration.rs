@@ -12,6 +12,7 @@ fn basic_floating_creation() {
         y: 200.0,
         side: Side::Bottom,
         align: Align::Center,
+        middleware_data: MiddlewareData::default(),
     };
 
     assert_eq!(position.x, 100.0);
@@ -32,6 +33,7 @@ fn floating_options_creation() {
         align: Align::End,
         side_offset: 15.0,
         align_offset: 8.0,
+        ..Default::default()
     };
 
     assert_eq!(options.side, Side::Top);
@@ -61,6 +63,7 @@ fn side_align_enums_work() {
                 y: 0.0,
                 side: *side,
                 align: *align,
+                middleware_data: MiddlewareData::default(),
             };
 
             assert_eq!(position.side, *side);
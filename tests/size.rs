@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex};
+
+use leptos_floating::*;
+
+const VIEWPORT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 800.0,
+};
+
+const RECTS: ElementRects = ElementRects {
+    reference: Rect {
+        x: 100.0,
+        y: 200.0,
+        width: 120.0,
+        height: 40.0,
+    },
+    floating: Rect {
+        x: 0.0,
+        y: 0.0,
+        width: 80.0,
+        height: 300.0,
+    },
+};
+
+fn size_data(options: FloatingOptions) -> SizeData {
+    let pos = calculate_position_from_rects(RECTS, VIEWPORT, options).unwrap();
+    *pos.middleware_data.get::<SizeData>().unwrap()
+}
+
+#[test]
+fn available_space_below_and_to_the_right() {
+    // Bottom places the element at (100, 240), leaving 560px below and 900px to the right
+    let data = size_data(FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        middleware: vec![size(SizeOptions::default())],
+        ..Default::default()
+    });
+
+    assert_eq!(
+        data,
+        SizeData {
+            available_width: 900.0,
+            available_height: 560.0,
+        }
+    );
+}
+
+#[test]
+fn available_width_for_right_side() {
+    // The reference ends at x=220, leaving 780px to the right
+    let data = size_data(FloatingOptions {
+        side: Side::Right,
+        align: Align::Start,
+        middleware: vec![size(SizeOptions::default())],
+        ..Default::default()
+    });
+
+    assert_eq!(data.available_width, 780.0);
+    assert_eq!(data.available_height, 600.0);
+}
+
+#[test]
+fn padding_reduces_available_space() {
+    let data = size_data(FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![size(SizeOptions {
+            padding: 10.0,
+            ..Default::default()
+        })],
+        ..Default::default()
+    });
+
+    assert_eq!(data.available_height, 550.0);
+}
+
+#[test]
+fn centered_element_is_limited_by_the_closer_edge() {
+    // Centered at x=120, the element can grow 120px in each direction
+    let data = size_data(FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Center,
+        middleware: vec![size(SizeOptions::default())],
+        ..Default::default()
+    });
+
+    assert_eq!(data.available_width, 320.0);
+}
+
+#[test]
+fn shift_makes_the_whole_boundary_available() {
+    let data = size_data(FloatingOptions {
+        side: Side::Bottom,
+        align: Align::Start,
+        middleware: vec![shift(ShiftOptions::default()), size(SizeOptions::default())],
+        ..Default::default()
+    });
+
+    assert_eq!(data.available_width, 1000.0);
+    assert_eq!(data.available_height, 560.0);
+}
+
+#[test]
+fn apply_receives_available_size() {
+    let applied = Arc::new(Mutex::new(None));
+    let options = FloatingOptions {
+        side: Side::Bottom,
+        middleware: vec![size(SizeOptions {
+            apply: Some(Arc::new({
+                let applied = Arc::clone(&applied);
+                move |state: &MiddlewareState, data: &SizeData| {
+                    *applied.lock().unwrap() = Some((state.side, *data));
+                }
+            })),
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    calculate_position_from_rects(RECTS, VIEWPORT, options).unwrap();
+
+    assert_eq!(
+        *applied.lock().unwrap(),
+        Some((
+            Side::Bottom,
+            SizeData {
+                available_width: 900.0,
+                available_height: 560.0,
+            }
+        ))
+    );
+}